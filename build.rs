@@ -8,6 +8,7 @@ const MIN_DIMS: usize = 2;
 const MIN_ORDER: usize = 1;
 
 const MAX_DIMS: usize = 4;
+// The highest order that gets a `BezNo` type alias and a `BezCurve` implementation
 const MAX_ORDER: usize = 6;
 
 fn main() {
@@ -33,11 +34,25 @@ fn main() {
         writeln!(file, "}}}}").unwrap();
     }
 
-    // Create one-dimensional bezier polynomials
+    // Create bezier curve implementations. Order 0 doesn't get a named alias, but it needs to be a
+    // `BezCurve` to be the derivative and reduction of order 1.
+    for order in 0..(MAX_ORDER + 1) {
+        let elevated = if order == MAX_ORDER {
+            format!("NBez<F, P, [P; {}]>", order + 2)
+        } else {
+            format!("Bez<F, P, {}>", order + 1)
+        };
+        let lower = order.saturating_sub(1);
+
+        writeln!(file, "bez_curve!{{{}; elevated {}; derivative Bez<F, P::Vector, {}>; reduced Bez<F, P, {}>}}",
+                 order, elevated, lower, lower).unwrap();
+    }
+
+    // Create named aliases
     for order in MIN_ORDER..(MAX_ORDER + 1) {
-        writeln!(file, "n_bezier!{{\"Order {0} bezier curve\", {0}, {1}; Bez{0}o {{", order, sum(order)).unwrap();
+        writeln!(file, "n_bezier!{{\"Order {0} bezier curve\", {0}; Bez{0}o, Bez{0}oPoints {{", order).unwrap();
         for o in 0..(order + 1) {
-            write!(file, "    {}: {}", get_param_name(o, order), combination(order, o)).unwrap();
+            write!(file, "    {}", get_param_name(o, order)).unwrap();
 
             // Insert commas necessary to seperate parameter names. 
             if o != order {
@@ -46,29 +61,8 @@ fn main() {
                 writeln!(file, "")
             }.unwrap();
         }
-
-
-        // Order of the derivative of the polynomial
-        let dorder = order - 1;
-        
-        writeln!(file, "}} {{").unwrap();
-        writeln!(file, "    {};", get_param_name(0, order)).unwrap();
-        for o in 0..order {
-            write!(file, "    {}, {}: {}", get_param_name(o, order), get_param_name(o + 1, order), combination(dorder, o)).unwrap();
-            
-            if o != dorder {
-                writeln!(file, ",")
-            } else {
-                writeln!(file, ";")
-            }.unwrap();
-        }
-        writeln!(file, "    {};", get_param_name(order, order)).unwrap();
-
-        if order == MAX_ORDER {
-            writeln!(file, "}} elevated NBez<F, P, [P; {}]> }}", order + 2).unwrap();
-        } else {
-            writeln!(file, "}} elevated Bez{}o<F, P> }}", order + 1).unwrap();
-        }
+        writeln!(file, "}}}}").unwrap();
+        writeln!(file, "n_rbezier!{{\"Order {0} rational bezier curve\", {0}; RBez{0}o}}", order).unwrap();
     }
 }
 
@@ -83,26 +77,3 @@ fn get_param_name(param_number: usize, poly_order: usize) -> String {
         format!("ctrl{}", param_number - 1)
     }
 }
-
-fn combination(n: usize, k: usize) -> usize {
-    factorial(n) / (factorial(k) * factorial(n - k))
-}
-
-fn factorial(n: usize) -> usize {
-    match n {
-        0 => 1,
-        _ => n * factorial(n-1)
-    }
-}
-
-/// Get the sum of all numbers on the interval [0, n]
-fn sum(mut n: usize) -> usize {
-    let mut acc = 0;
-
-    while n > 0 {
-        acc += n;
-        n -= 1;
-    }
-    
-    acc
-}
\ No newline at end of file
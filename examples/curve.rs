@@ -5,7 +5,7 @@ extern crate gfx;
 extern crate gfx_window_glutin;
 extern crate glutin;
 
-use nbez::{BezCurve, BezChain, Bez3o, Point2d};
+use nbez::{BezCurve, OwnedBezCurve, BezChain, Bez3o, Point2d};
 
 use gfx::traits::FactoryExt;
 use gfx::{Factory, Device, Primitive, BufferRole, Bind, Slice, IndexBuffer};
@@ -0,0 +1,218 @@
+use std::convert::{AsRef, AsMut};
+use std::marker::PhantomData;
+use std::fmt::{Debug, Formatter};
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use num_traits::identities::Zero;
+
+use super::{BezCurve, OwnedBezCurve, OrderStatic, Float, Point, MetricVector, de_casteljau, de_casteljau_split, reduce};
+
+/// Checks on the orders passed to `Bez`'s `_static` methods. The constants fail to evaluate for the
+/// wrong orders, which stops compilation wherever they're used.
+struct OrderCheck<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> OrderCheck<N, M> {
+    const ONE_HIGHER: () = assert!(M == N + 1, "the elevated curve must be one order higher");
+    const ONE_LOWER: () = assert!(M + 1 == N || (0 == N && 0 == M), "the curve must be one order lower");
+}
+
+/// A stack-allocated bezier curve of order `N`, containing `N + 1` points.
+///
+/// Every order has the same struct layout: the start point, followed by the remaining `N` points.
+/// `BezCurve` is implemented for the orders with a `BezNo` alias, and for order `0`. Each of those
+/// elevates to, differentiates to and reduces to the `Bez` of the neighbouring order, which can't be
+/// written in terms of `N` on a stable compiler, so the other orders aren't `BezCurve`s. Their points
+/// can still be evaluated as an `NBez`.
+///
+/// The aliased orders dereference to a struct with each of their points as a similarly-named field,
+/// such as `curve.start`, `curve.ctrl0` and `curve.end`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Bez<F, P, const N: usize>
+        where F: Float,
+              P: Point<F> {
+    start: P,
+    rest: [P; N],
+    __marker: PhantomData<F>
+}
+
+impl<F, P, const N: usize> Bez<F, P, N>
+        where F: Float,
+              P: Point<F> {
+    /// Create a new bezier curve from its first point and the `N` points that follow it.
+    #[inline]
+    pub fn from_points(start: P, rest: [P; N]) -> Bez<F, P, N> {
+        Bez {
+            start,
+            rest,
+            __marker: PhantomData
+        }
+    }
+
+    /// Create a curve from a slice. Fails if the slice's length does not match the curve's order + 1.
+    pub(crate) fn from_slice_static(slice: &[P]) -> Option<Bez<F, P, N>> {
+        if slice.len() != N + 1 {
+            None
+        } else {
+            let mut rest = [slice[0]; N];
+            rest.copy_from_slice(&slice[1..]);
+            Some(Bez::from_points(slice[0], rest))
+        }
+    }
+
+    /// Elevate the curve order, getting a stack-allocated curve that is one order higher but gives the
+    /// same results upon interpolation. `M` must be `N + 1`, which is checked at compiletime.
+    pub(crate) fn elevate_static<const M: usize>(&self) -> Bez<F, P, M> {
+        let () = OrderCheck::<N, M>::ONE_HIGHER;
+
+        let mut elevated = Bez::from_points(self.start, [self.start; M]);
+        {
+            let points = self.as_ref();
+            let el_points = elevated.as_mut();
+            let order = F::from_usize(M).unwrap();
+            for i in 1..M {
+                let factor = F::from_usize(i).unwrap();
+                el_points[i] = (points[i - 1] * factor + points[i] * (order - factor)) / order;
+            }
+            el_points[M] = points[N];
+        }
+
+        elevated
+    }
+
+    /// Get the curve's derivative as a stack-allocated curve, with the differences between adjacent
+    /// points scaled by `N` as its points. `M` must be `N - 1`, or `0` for a curve of order `0`, whose
+    /// derivative is a single zero vector. That's checked at compiletime.
+    pub(crate) fn derivative_static<const M: usize>(&self) -> Bez<F, P::Vector, M> {
+        let () = OrderCheck::<N, M>::ONE_LOWER;
+
+        let zero = <P::Vector as Zero>::zero();
+        let mut derivative = Bez::from_points(zero, [zero; M]);
+        {
+            let points = self.as_ref();
+            let dpoints = derivative.as_mut();
+            let order = F::from_usize(N).unwrap();
+            for i in 0..N {
+                dpoints[i] = (points[i + 1] - points[i]).into() * order;
+            }
+        }
+
+        derivative
+    }
+
+    /// Reduce the curve order, getting the stack-allocated curve one order lower that best fits this
+    /// one along with an upper bound on the distance between the two curves. `M` must be `N - 1`, or
+    /// `0` for a curve of order `0`, which is checked at compiletime. See `BezCurve::reduce` for how
+    /// the curve is fit.
+    pub(crate) fn reduce_static<const M: usize>(&self) -> (Bez<F, P, M>, F)
+            where P::Vector: MetricVector<F> {
+        let () = OrderCheck::<N, M>::ONE_LOWER;
+
+        let points = reduce::reduce_points(self.as_ref(), M);
+        let error = reduce::error_bound(self.as_ref(), &points);
+        (Bez::from_slice_static(&points).unwrap(), error)
+    }
+
+    /// Perform interpolation on the curve with no range bounds, using de Casteljau's algorithm with a
+    /// copy of the curve as the scratch space.
+    pub(crate) fn interp_static(&self, t: F) -> P {
+        let mut lerps = *self;
+        de_casteljau(lerps.as_mut(), t)
+    }
+
+    /// Get the slope of the curve with no range bounds. The derivative's points are the differences
+    /// between adjacent points, which get evaluated the same way `interp_static` evaluates the curve.
+    pub(crate) fn slope_static(&self, t: F) -> P::Vector {
+        if 0 == N {
+            return P::zero().into();
+        }
+
+        let mut dpoints = *self;
+        {
+            let dpoints = dpoints.as_mut();
+            for i in 0..N {
+                dpoints[i] = dpoints[i + 1] - dpoints[i];
+            }
+        }
+
+        (de_casteljau(&mut dpoints.as_mut()[..N], t) * F::from_usize(N).unwrap()).into()
+    }
+}
+
+impl<F, P, const N: usize> OwnedBezCurve<F> for Bez<F, P, N>
+        where F: Float,
+              P: Point<F>,
+              Bez<F, P, N>: BezCurve<F, Point = P> {
+    #[inline]
+    fn from_slice(slice: &[P]) -> Option<Bez<F, P, N>> {
+        Bez::from_slice_static(slice)
+    }
+
+    fn split_unbounded(&self, t: F) -> (Bez<F, P, N>, Bez<F, P, N>) {
+        let mut lerps = *self;
+        let mut left = *self;
+        let mut right = *self;
+        de_casteljau_split(self.as_ref(), t, lerps.as_mut(), left.as_mut(), right.as_mut());
+
+        (left, right)
+    }
+}
+
+impl<F, P, const N: usize> OrderStatic for Bez<F, P, N>
+        where F: Float,
+              P: Point<F> {
+    #[inline]
+    fn order_static() -> usize {
+        N
+    }
+}
+
+impl<F, P, const N: usize> AsRef<[P]> for Bez<F, P, N>
+        where F: Float,
+              P: Point<F> {
+    fn as_ref(&self) -> &[P] {
+        // `Bez` is `repr(C)`, so `start` is directly followed by `rest` in memory.
+        unsafe {
+            slice::from_raw_parts(self as *const Bez<F, P, N> as *const P, N + 1)
+        }
+    }
+}
+
+impl<F, P, const N: usize> AsMut<[P]> for Bez<F, P, N>
+        where F: Float,
+              P: Point<F> {
+    fn as_mut(&mut self) -> &mut [P] {
+        unsafe {
+            slice::from_raw_parts_mut(self as *mut Bez<F, P, N> as *mut P, N + 1)
+        }
+    }
+}
+
+impl<F, P, const N: usize> Index<usize> for Bez<F, P, N>
+        where F: Float,
+              P: Point<F> {
+    type Output = P;
+
+    fn index(&self, index: usize) -> &P {
+        &self.as_ref()[index]
+    }
+}
+
+impl<F, P, const N: usize> IndexMut<usize> for Bez<F, P, N>
+        where F: Float,
+              P: Point<F> {
+    fn index_mut(&mut self, index: usize) -> &mut P {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl<F, P, const N: usize> Debug for Bez<F, P, N>
+        where F: Float,
+              P: Point<F> + Debug {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), ::std::fmt::Error> {
+        f.debug_tuple("Bez")
+            .field(&self.as_ref())
+            .finish()
+    }
+}
@@ -0,0 +1,114 @@
+use super::{BezCurve, OwnedBezCurve, Float, total_cmp};
+use super::solve::bernstein_roots;
+
+/// Grow the box between `min` and `max` so that it contains `point`.
+pub(crate) fn include_point<F, P>(min: &mut P, max: &mut P, point: &P)
+        where F: Float,
+              P: AsRef<[F]> + AsMut<[F]> {
+    let components = point.as_ref();
+    for (axis, component) in components.iter().enumerate() {
+        if *component < min.as_ref()[axis] {
+            min.as_mut()[axis] = *component;
+        }
+        if *component > max.as_ref()[axis] {
+            max.as_mut()[axis] = *component;
+        }
+    }
+}
+
+/// Get the `t`s at which the given axis of the curve reaches a local extremum, in increasing order.
+///
+/// The derivative of the curve along the axis is a bernstein polynomial one order lower than the curve,
+/// with coefficients proportional to the differences between adjacent control points. So, the extrema
+/// are the roots of that polynomial, or of the derivative's numerator for rational curves.
+pub(crate) fn axis_extrema<F, B>(curve: &B, axis: usize) -> Vec<F>
+        where F: Float,
+              B: BezCurve<F>,
+              B::Point: AsRef<[F]> {
+    bernstein_roots(&curve.derivative_numerator(|point| point.as_ref()[axis]))
+}
+
+/// Get the smallest box containing all of the curve's control points.
+pub(crate) fn control_box<F, B>(curve: &B) -> (B::Point, B::Point)
+        where F: Float,
+              B: BezCurve<F>,
+              B::Point: AsRef<[F]> + AsMut<[F]> {
+    let points = curve.points();
+    let mut min = points[0];
+    let mut max = points[0];
+    for point in &points[1..] {
+        include_point(&mut min, &mut max, point);
+    }
+
+    (min, max)
+}
+
+/// Get the smallest box containing the curve between `0.0` and `1.0`. Along each axis, the curve can
+/// only reach its minimum and maximum at its ends or at one of the axis's extrema.
+pub(crate) fn bounding_box<F, B>(curve: &B) -> (B::Point, B::Point)
+        where F: Float,
+              B: BezCurve<F>,
+              B::Point: AsRef<[F]> + AsMut<[F]> {
+    let points = curve.points();
+    let mut min = points[0];
+    let mut max = points[0];
+    include_point(&mut min, &mut max, &points[points.len() - 1]);
+
+    for axis in 0..points[0].as_ref().len() {
+        for t in axis_extrema(curve, axis) {
+            include_point(&mut min, &mut max, &curve.interp_unbounded(t));
+        }
+    }
+
+    (min, max)
+}
+
+/// Get the `t`s at which any axis of the curve reaches a local extremum, in increasing order and with
+/// duplicates removed.
+pub(crate) fn extrema<F, B>(curve: &B) -> Vec<F>
+        where F: Float,
+              B: BezCurve<F>,
+              B::Point: AsRef<[F]> {
+    let dims = curve.points()[0].as_ref().len();
+    let mut extrema: Vec<F> = (0..dims).flat_map(|axis| axis_extrema(curve, axis)).collect();
+    extrema.sort_by(total_cmp);
+    extrema.dedup();
+    extrema
+}
+
+/// Split the curve at each of the given `t`s, which must be in increasing order. `t`s outside of
+/// `0.0` to `1.0` exclusive are skipped.
+pub(crate) fn split_at<F, B>(curve: &B, ts: &[F]) -> Vec<B>
+        where F: Float,
+              B: OwnedBezCurve<F> {
+    let mut curves = Vec::new();
+    let mut rest = curve.clone();
+    // The `t` on the original curve that `rest` starts at
+    let mut rest_start = F::zero();
+
+    for t in ts.iter().cloned() {
+        // `t`s at the ends of the curve don't need a split.
+        if t <= F::zero() || t >= F::one() {
+            continue;
+        }
+
+        // `rest` only covers the part of the original curve after `rest_start`, so `t` has to get
+        // rescaled onto it.
+        let (left, right) = rest.split_unbounded((t - rest_start) / (F::one() - rest_start));
+        curves.push(left);
+        rest = right;
+        rest_start = t;
+    }
+
+    curves.push(rest);
+    curves
+}
+
+/// Split the curve at each of its extrema, so that every axis of each of the resulting curves is
+/// monotonic.
+pub(crate) fn split_monotone<F, B>(curve: &B) -> Vec<B>
+        where F: Float,
+              B: OwnedBezCurve<F>,
+              B::Point: AsRef<[F]> {
+    split_at(curve, &extrema(curve))
+}
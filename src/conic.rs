@@ -0,0 +1,254 @@
+use super::{Bez, Bez2o, Bez3o, BezChain, OwnedBezCurve, Float, Point2d, RBez, RBez2o, Transform2d, Vector2d};
+
+/// Get the transform that maps the unit circle onto the ellipse with the given center and radii, whose
+/// `x` radius is rotated counterclockwise by `rotation` radians.
+fn ellipse_transform<F: Float>(center: Point2d<F>, radii: Vector2d<F>, rotation: F) -> Transform2d<F> {
+    Transform2d::scale(radii.x, radii.y)
+        .then(&Transform2d::rotate(rotation))
+        .then(&Transform2d::translate(center.x, center.y))
+}
+
+/// Get the transform that maps a conic's local coordinates onto the plane, given its vertex and
+/// focus. The local `x` axis points from the vertex towards the focus, and the local `y` axis is
+/// perpendicular to it, counterclockwise. Returns `None` if the vertex and focus are the same point.
+fn axis_transform<F: Float>(origin: Point2d<F>, vertex: Point2d<F>, focus: Point2d<F>) -> Option<(Transform2d<F>, F)> {
+    let axis: Vector2d<F> = (focus - vertex).into();
+    let focal_length = axis.len();
+    if focal_length == F::zero() {
+        return None;
+    }
+
+    let x = axis / focal_length;
+    let y = x.perp();
+    let transform = Transform2d::new([[x.x, y.x, origin.x], [x.y, y.y, origin.y]]);
+    Some((transform, focal_length))
+}
+
+/// Get the points of the cubic that approximates the unit circle from `start_angle` to
+/// `start_angle + sweep`, along with an upper bound on its distance from the circle.
+///
+/// The inner points are placed along the tangents at the ends, `4/3 * tan(sweep / 4)` away from them,
+/// which puts the middle of the curve exactly on the circle. The furthest the rest of the curve gets
+/// from the circle is bounded by `2 * sin(sweep / 4)^6 / (27 * cos(sweep / 4)^2)`.
+fn unit_cubic_arc<F: Float>(start_angle: F, sweep: F) -> (Bez<F, Point2d<F>, 3>, F) {
+    let four = F::from_f32(4.0).unwrap();
+    let (sin0, cos0) = start_angle.sin_cos();
+    let (sin1, cos1) = (start_angle + sweep).sin_cos();
+    let quarter = sweep / four;
+    let k = quarter.tan() * four / F::from_f32(3.0).unwrap();
+
+    let curve = Bez3o::new(
+        Point2d::new(cos0, sin0),
+        Point2d::new(cos0 - k * sin0, sin0 + k * cos0),
+        Point2d::new(cos1 + k * sin1, sin1 - k * cos1),
+        Point2d::new(cos1, sin1)
+    );
+    let error = F::from_f32(2.0).unwrap() * quarter.sin().powi(6) / (F::from_f32(27.0).unwrap() * quarter.cos().powi(2));
+    (curve, error.abs())
+}
+
+impl<F: Float> RBez<F, Point2d<F>, 2> {
+    /// Create the exact arc of the circle with the given center and radius, going counterclockwise from
+    /// `start_angle` to `start_angle + sweep`, or clockwise if `sweep` is negative. Angles are in
+    /// radians. Returns `None` if the arc is a half turn or more, as the middle point's weight would
+    /// have to be zero or less; split larger arcs into several smaller ones.
+    pub fn circle_arc(center: Point2d<F>, radius: F, start_angle: F, sweep: F) -> Option<RBez<F, Point2d<F>, 2>> {
+        RBez::ellipse_arc(center, Vector2d::new(radius, radius), F::zero(), start_angle, sweep)
+    }
+
+    /// Create the exact arc of an ellipse, which has the `x` radius of `radii` rotated counterclockwise
+    /// by `rotation` radians. The angles are parametric, so the arc runs over the points
+    /// `(radii.x * cos(angle), radii.y * sin(angle))` before they're rotated and moved to `center`.
+    /// Returns `None` if the arc is a half turn or more.
+    ///
+    /// The arc is a circular arc that has been affinely transformed onto the ellipse. The circular arc's
+    /// middle point is where the tangents at its ends meet, and has a weight of `cos(sweep / 2)`.
+    pub fn ellipse_arc(center: Point2d<F>, radii: Vector2d<F>, rotation: F, start_angle: F, sweep: F) -> Option<RBez<F, Point2d<F>, 2>> {
+        if sweep.abs() >= F::from_f64(::std::f64::consts::PI).unwrap() {
+            return None;
+        }
+
+        let half = sweep / F::from_f32(2.0).unwrap();
+        let weight = half.cos();
+
+        let (sin0, cos0) = start_angle.sin_cos();
+        let (sin_mid, cos_mid) = (start_angle + half).sin_cos();
+        let (sin1, cos1) = (start_angle + sweep).sin_cos();
+        let unit = RBez2o::new(
+            Bez2o::new(
+                Point2d::new(cos0, sin0),
+                Point2d::new(cos_mid / weight, sin_mid / weight),
+                Point2d::new(cos1, sin1)
+            ),
+            [F::one(), weight, F::one()]
+        );
+
+        Some(unit.transform(&ellipse_transform(center, radii, rotation)))
+    }
+
+    /// Create part of the parabola with the given vertex and focus. The parabola runs through the
+    /// points `vertex + y * u + x * u^2 / (4 * f)`, where `x` is the unit vector from the vertex towards
+    /// the focus, `y` is `x` rotated a quarter turn counterclockwise, and `f` is the distance from the
+    /// vertex to the focus. The part between `u0` and `u1` gets returned, or `None` if the vertex and
+    /// focus are the same point.
+    ///
+    /// Parabolas don't need any weights, so every point has a weight of `1.0`.
+    pub fn parabola(vertex: Point2d<F>, focus: Point2d<F>, u0: F, u1: F) -> Option<RBez<F, Point2d<F>, 2>> {
+        let (transform, focal_length) = axis_transform(vertex, vertex, focus)?;
+        let four_f = focal_length * F::from_f32(4.0).unwrap();
+
+        // The middle point is where the tangents at the ends meet.
+        let local = Bez2o::new(
+            Point2d::new(u0 * u0 / four_f, u0),
+            Point2d::new(u0 * u1 / four_f, (u0 + u1) / F::from_f32(2.0).unwrap()),
+            Point2d::new(u1 * u1 / four_f, u1)
+        );
+        Some(RBez::from(local).transform(&transform))
+    }
+
+    /// Create part of one branch of the hyperbola with the given vertex, focus and eccentricity, which
+    /// must be greater than `1.0`. Returns `None` if it isn't, or if the vertex and focus are the same
+    /// point.
+    ///
+    /// With `a` as the distance from the hyperbola's center to its vertex and `b` as its semi-minor
+    /// axis, the branch runs through `center + x * a * cosh(s) + y * b * sinh(s)`, where `x` and `y` are
+    /// the same axes as in `parabola`. The part between `s0` and `s1` gets returned. Like with circular
+    /// arcs, the middle point is where the tangents at the ends meet, but its weight is
+    /// `cosh((s1 - s0) / 2)` instead.
+    pub fn hyperbola(vertex: Point2d<F>, focus: Point2d<F>, eccentricity: F, s0: F, s1: F) -> Option<RBez<F, Point2d<F>, 2>> {
+        let one = F::one();
+        if eccentricity <= one || vertex == focus {
+            return None;
+        }
+
+        // The focus is `a * (e - 1)` past the vertex, and the center is `a` before it.
+        let focal_distance = Vector2d::from(focus - vertex).len();
+        let a = focal_distance / (eccentricity - one);
+        let b = a * (eccentricity * eccentricity - one).sqrt();
+        let center = vertex + (vertex - focus) * (a / focal_distance);
+        let (transform, _) = axis_transform(center, vertex, focus)?;
+
+        let half = (s1 - s0) / F::from_f32(2.0).unwrap();
+        let mid = s0 + half;
+        let weight = half.cosh();
+        let local = RBez2o::new(
+            Bez2o::new(
+                Point2d::new(a * s0.cosh(), b * s0.sinh()),
+                Point2d::new(a * mid.cosh() / weight, b * mid.sinh() / weight),
+                Point2d::new(a * s1.cosh(), b * s1.sinh())
+            ),
+            [one, weight, one]
+        );
+        Some(local.transform(&transform))
+    }
+}
+
+impl<F: Float> Bez<F, Point2d<F>, 3> {
+    /// Approximate the arc of the circle with the given center and radius with a cubic, for uses that
+    /// can't take a rational curve. The arc goes from `start_angle` to `start_angle + sweep` the same way
+    /// as in `RBez::circle_arc`. Returns the cubic along with an upper bound on its distance from the
+    /// circle, or `None` if the arc is a full turn or more.
+    ///
+    /// The bound is about `2.7e-4 * radius` for a quarter turn, and grows with the sixth power of the
+    /// sweep, so larger arcs should get split into quarter turns or less.
+    pub fn circle_arc(center: Point2d<F>, radius: F, start_angle: F, sweep: F) -> Option<(Bez<F, Point2d<F>, 3>, F)> {
+        Bez::ellipse_arc(center, Vector2d::new(radius, radius), F::zero(), start_angle, sweep)
+    }
+
+    /// Approximate the arc of an ellipse with a cubic, with the arc given the same way as in
+    /// `RBez::ellipse_arc`. Returns the cubic along with an upper bound on its distance from the
+    /// ellipse, or `None` if the arc is a full turn or more.
+    pub fn ellipse_arc(center: Point2d<F>, radii: Vector2d<F>, rotation: F, start_angle: F, sweep: F) -> Option<(Bez<F, Point2d<F>, 3>, F)> {
+        let full_turn = F::from_f64(::std::f64::consts::PI * 2.0).unwrap();
+        if sweep.abs() >= full_turn {
+            return None;
+        }
+
+        // Scaling the circle onto the ellipse can't move the cubic any further away from the curve than
+        // the larger radius scales the circle's bound.
+        let (unit, error) = unit_cubic_arc(start_angle, sweep);
+        let scale = radii.x.abs().max(radii.y.abs());
+        Some((unit.transform(&ellipse_transform(center, radii, rotation)), error * scale))
+    }
+}
+
+impl<F: Float> BezChain<F, Bez<F, Point2d<F>, 3>, Vec<Point2d<F>>> {
+    /// Convert an SVG elliptical arc into a chain of cubics, using the arc's parameters as they appear
+    /// in an SVG path's `A` command. The arc goes from `from` to `to` along an ellipse with the given
+    /// radii, whose `x` axis is rotated counterclockwise by `rotation` radians. Of the four arcs that
+    /// fit, `large_arc` picks the one sweeping more than a half turn, and `sweep` picks the one going
+    /// in the direction of increasing angles.
+    ///
+    /// Following the SVG specification, radii that are too small to reach between the two points get
+    /// scaled up until they just fit, an arc with a zero radius becomes a straight line, and an arc
+    /// whose ends are the same point is empty. The arc is split into cubics covering a quarter turn or
+    /// less, each of which is within about `2.7e-4` times the larger radius of the ellipse.
+    pub fn from_svg_arc(from: Point2d<F>, radii: Vector2d<F>, rotation: F, large_arc: bool, sweep: bool, to: Point2d<F>) -> BezChain<F, Bez<F, Point2d<F>, 3>, Vec<Point2d<F>>> {
+        let (zero, one, two) = (F::zero(), F::one(), F::from_f32(2.0).unwrap());
+        if from == to {
+            return BezChain::from_container(vec![from]);
+        }
+
+        let (mut rx, mut ry) = (radii.x.abs(), radii.y.abs());
+        if rx == zero || ry == zero {
+            let third = (to - from) / F::from_f32(3.0).unwrap();
+            return BezChain::from_container(vec![from, from + third, to - third, to]);
+        }
+
+        // Move the midpoint between the ends to the origin and undo the ellipse's rotation.
+        let (sin, cos) = rotation.sin_cos();
+        let half_diff = (from - to) / two;
+        let x1 = cos * half_diff.x + sin * half_diff.y;
+        let y1 = cos * half_diff.y - sin * half_diff.x;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > one {
+            rx = rx * lambda.sqrt();
+            ry = ry * lambda.sqrt();
+        }
+
+        // Find the center of the ellipse in the same rotated space.
+        let (rx_sq, ry_sq) = (rx * rx, ry * ry);
+        let numerator = rx_sq * ry_sq - rx_sq * y1 * y1 - ry_sq * x1 * x1;
+        let denominator = rx_sq * y1 * y1 + ry_sq * x1 * x1;
+        let mut coeff = (numerator / denominator).max(zero).sqrt();
+        if large_arc == sweep {
+            coeff = -coeff;
+        }
+        let cx1 = coeff * rx * y1 / ry;
+        let cy1 = -coeff * ry * x1 / rx;
+        let mid = (from + to) / two;
+        let center = Point2d::new(cos * cx1 - sin * cy1 + mid.x, sin * cx1 + cos * cy1 + mid.y);
+
+        // The angles are measured on the unit circle that gets scaled onto the ellipse.
+        let angle = |x: F, y: F| y.atan2(x);
+        let start_angle = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+        let end_angle = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+        let full_turn = F::from_f64(::std::f64::consts::PI * 2.0).unwrap();
+        let mut delta = end_angle - start_angle;
+        if sweep && delta < zero {
+            delta = delta + full_turn;
+        } else if !sweep && delta > zero {
+            delta = delta - full_turn;
+        }
+
+        let quarter_turn = full_turn / F::from_f32(4.0).unwrap();
+        let segments = (delta.abs() / quarter_turn).ceil().max(one);
+        let segment_sweep = delta / segments;
+        let transform = ellipse_transform(center, Vector2d::new(rx, ry), rotation);
+
+        let segments = segments.to_usize().unwrap();
+        let mut points = Vec::with_capacity(segments * 3 + 1);
+        points.push(from);
+        for i in 0..segments {
+            let segment_start = start_angle + segment_sweep * F::from_usize(i).unwrap();
+            let (unit, _) = unit_cubic_arc(segment_start, segment_sweep);
+            let curve = unit.transform(&transform);
+            points.extend_from_slice(&curve.as_ref()[1..]);
+        }
+
+        // Rounding errors shouldn't move the end of the chain off of the arc's end point.
+        points[segments * 3] = to;
+        BezChain::from_container(points)
+    }
+}
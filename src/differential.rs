@@ -0,0 +1,44 @@
+use super::{BezCurve, Float, Point, MetricVector, Point2d, Vector2d};
+
+/// Get the curvature of the curve at `t`, which is the reciprocal of the radius of the circle that
+/// best fits the curve at that point.
+///
+/// Written in terms of the first and second derivatives `d1` and `d2`, the curvature is
+/// `|d1 × d2| / |d1|^3`. The cross product only exists in 3 dimensions, but its length is equal to
+/// `sqrt(|d1|^2 * |d2|^2 - (d1 · d2)^2)` in any number of dimensions.
+///
+/// The curvature isn't defined where the curve stops moving, such as at a cusp, so this returns `None`
+/// wherever `d1` is zero.
+pub(crate) fn curvature<F, B>(curve: &B, t: F) -> Option<F>
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    let d1 = curve.slope_unbounded(t);
+    let d2 = curve.second_derivative_unbounded(t);
+
+    let d1_len_sq = d1.dot(d1);
+    if d1_len_sq == F::zero() {
+        return None;
+    }
+
+    let d1_d2 = d1.dot(d2);
+    // Rounding can push this slightly below zero for nearly-straight curves.
+    let cross_len_sq = (d1_len_sq * d2.dot(d2) - d1_d2 * d1_d2).max(F::zero());
+
+    Some(cross_len_sq.sqrt() / (d1_len_sq * d1_len_sq.sqrt()))
+}
+
+/// Get the signed curvature of a 2D curve at `t`. It's positive when the curve turns counterclockwise
+/// and negative when it turns clockwise. Like `curvature`, it's `None` wherever the slope is zero.
+pub(crate) fn signed_curvature<F, B>(curve: &B, t: F) -> Option<F>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let d1: Vector2d<F> = curve.slope_unbounded(t);
+    let d2: Vector2d<F> = curve.second_derivative_unbounded(t);
+    let d1_len = d1.len();
+    if d1_len == F::zero() {
+        return None;
+    }
+
+    Some((d1.x * d2.y - d1.y * d2.x) / (d1_len * d1_len * d1_len))
+}
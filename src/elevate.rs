@@ -0,0 +1,26 @@
+use super::{Float, Linear};
+use super::solve::binomial;
+
+/// Get the points of the curve elevated to the given order, which must be at least the curve's own
+/// order.
+///
+/// Rather than elevating one order at a time, this uses the closed-form formula for elevating by `r`
+/// orders at once: `q[i] = sum(C(n, j) * C(r, i - j) * p[j]) / C(n + r, i)`, where `n` is the curve's
+/// order and `j` ranges over the points that contribute to `q[i]`.
+pub(crate) fn elevate_points<F, P>(points: &[P], order: usize) -> Vec<P>
+        where F: Float,
+              P: Linear<F> {
+    let n = points.len() - 1;
+    let r = order - n;
+
+    (0..order + 1)
+        .map(|i| {
+            let start = i.saturating_sub(r);
+            let end = i.min(n);
+            let sum = (start..end + 1).fold(P::zero(), |acc, j| {
+                acc + points[j] * (binomial::<F>(n, j) * binomial(r, i - j))
+            });
+            sum / binomial(order, i)
+        })
+        .collect()
+}
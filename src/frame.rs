@@ -0,0 +1,197 @@
+use super::{BezCurve, Float, InterpIter, MetricVector, Point3d, Vector3d};
+
+impl<F: Float> Vector3d<F> {
+    /// Get the cross product of `self` and `other`, which is perpendicular to both of them and has a
+    /// length equal to the area of the parallelogram they span.
+    pub fn cross(self, other: Vector3d<F>) -> Vector3d<F> {
+        Vector3d::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x
+        )
+    }
+}
+
+/// A set of three perpendicular unit vectors attached to a point on a curve, with `tangent` pointing
+/// along the curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame<F: Float> {
+    /// The point on the curve that the frame is attached to
+    pub point: Point3d<F>,
+    pub tangent: Vector3d<F>,
+    pub normal: Vector3d<F>,
+    /// The cross product of `tangent` and `normal`
+    pub binormal: Vector3d<F>
+}
+
+/// Get the unit tangent of the curve at `t`, or `None` if the curve's slope is zero.
+pub(crate) fn tangent<F, B>(curve: &B, t: F) -> Option<Vector3d<F>>
+        where F: Float,
+              B: BezCurve<F, Point = Point3d<F>> {
+    let slope = curve.slope_unbounded(t);
+    if slope.len() == F::zero() {
+        None
+    } else {
+        Some(slope.normalize())
+    }
+}
+
+/// Get the unit binormal of the curve at `t`, which is the direction of the cross product of the
+/// first and second derivatives. Returns `None` if the curve is straight at `t`.
+pub(crate) fn binormal<F, B>(curve: &B, t: F) -> Option<Vector3d<F>>
+        where F: Float,
+              B: BezCurve<F, Point = Point3d<F>> {
+    let cross = curve.slope_unbounded(t).cross(curve.second_derivative_unbounded(t));
+    if cross.len() == F::zero() {
+        None
+    } else {
+        Some(cross.normalize())
+    }
+}
+
+/// Get the Frenet frame of the curve at `t`, which is undefined wherever the curve is straight.
+pub(crate) fn frenet_frame<F, B>(curve: &B, t: F) -> Option<Frame<F>>
+        where F: Float,
+              B: BezCurve<F, Point = Point3d<F>> {
+    let tangent = tangent(curve, t)?;
+    let binormal = binormal(curve, t)?;
+
+    Some(Frame {
+        point: curve.interp_unbounded(t),
+        tangent,
+        normal: binormal.cross(tangent),
+        binormal
+    })
+}
+
+/// Get the torsion of the curve at `t`, which is how quickly the curve twists out of the plane it's
+/// curving in. Returns `None` if the curve is straight at `t`, as that plane isn't defined there.
+///
+/// Written in terms of the first three derivatives, the torsion is `(d1 × d2) · d3 / |d1 × d2|^2`.
+pub(crate) fn torsion<F, B>(curve: &B, t: F) -> Option<F>
+        where F: Float,
+              B: BezCurve<F, Point = Point3d<F>> {
+    let cross = curve.slope_unbounded(t).cross(curve.second_derivative_unbounded(t));
+    let cross_len_sq = cross.dot(cross);
+    if cross_len_sq == F::zero() {
+        return None;
+    }
+
+    let d3 = curve.derivative().derivative().slope_unbounded(t);
+    Some(cross.dot(d3) / cross_len_sq)
+}
+
+/// Get a unit vector perpendicular to `v`, for use as a normal where the curve doesn't define one.
+fn any_perpendicular<F: Float>(v: Vector3d<F>) -> Vector3d<F> {
+    // Crossing with whichever axis `v` is furthest from avoids a near-zero result.
+    let axis = if v.x.abs() <= v.y.abs() && v.x.abs() <= v.z.abs() {
+        Vector3d::new(F::one(), F::zero(), F::zero())
+    } else if v.y.abs() <= v.z.abs() {
+        Vector3d::new(F::zero(), F::one(), F::zero())
+    } else {
+        Vector3d::new(F::zero(), F::zero(), F::one())
+    };
+
+    v.cross(axis).normalize()
+}
+
+/// Reflect `v` in the plane perpendicular to `normal`, given `normal`'s squared length.
+fn reflect<F: Float>(v: Vector3d<F>, normal: Vector3d<F>, normal_len_sq: F) -> Vector3d<F> {
+    v - normal * (F::from_f32(2.0).unwrap() * normal.dot(v) / normal_len_sq)
+}
+
+/// An iterator over rotation-minimizing frames sampled along a 3D curve, at the same `t`s as
+/// `InterpIter`.
+///
+/// Unlike the Frenet frame, a rotation-minimizing frame doesn't spin around the tangent as the curve
+/// twists, and doesn't flip over at inflections. Each frame is found from the previous one with the
+/// double reflection method: the previous frame is reflected across the plane halfway between the two
+/// sample points, then again to line its tangent up with the curve's.
+pub struct FrameIter<'a, F, B>
+        where F: Float,
+              B: 'a + BezCurve<F, Point = Point3d<F>> {
+    points: InterpIter<'a, F, B>,
+    /// The frame returned by the last call to `next`
+    prev: Option<Frame<F>>
+}
+
+impl<'a, F, B> FrameIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F, Point = Point3d<F>> {
+    pub(crate) fn new(curve: &'a B, samples: u32) -> FrameIter<'a, F, B> {
+        FrameIter {
+            points: curve.interp_iter(samples),
+            prev: None
+        }
+    }
+
+    /// Get the tangent at `t`. Wherever the curve's slope is zero, this falls back to the previous
+    /// frame's tangent, or to the direction between the first two distinct control points.
+    fn tangent(&self, t: F) -> Vector3d<F> {
+        let curve = self.points.curve;
+        tangent(curve, t)
+            .or_else(|| self.prev.map(|frame| frame.tangent))
+            .or_else(|| {
+                let points = curve.points();
+                points.windows(2)
+                    .map(|pair| Vector3d::from(pair[1] - pair[0]))
+                    .find(|v| v.len() != F::zero())
+                    .map(|v| v.normalize())
+            })
+            .unwrap_or_else(|| Vector3d::new(F::one(), F::zero(), F::zero()))
+    }
+}
+
+impl<'a, F, B> Iterator for FrameIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F, Point = Point3d<F>> {
+    type Item = Frame<F>;
+    fn next(&mut self) -> Option<Frame<F>> {
+        let t = F::from_u32(self.points.t_nodiv).unwrap() / self.points.samples;
+        let point = self.points.next()?;
+        let tangent = self.tangent(t);
+
+        let normal = match self.prev {
+            // The first frame starts off with the Frenet frame's normal where it's defined.
+            None => match binormal(self.points.curve, t) {
+                Some(binormal) => binormal.cross(tangent),
+                None => any_perpendicular(tangent)
+            },
+            Some(prev) => {
+                let v1: Vector3d<F> = (point - prev.point).into();
+                let c1 = v1.dot(v1);
+                if c1 == F::zero() {
+                    prev.normal
+                } else {
+                    let normal_l = reflect(prev.normal, v1, c1);
+                    let tangent_l = reflect(prev.tangent, v1, c1);
+
+                    let v2 = tangent - tangent_l;
+                    let c2 = v2.dot(v2);
+                    if c2 == F::zero() {
+                        normal_l
+                    } else {
+                        reflect(normal_l, v2, c2)
+                    }
+                }
+            }
+        };
+
+        let frame = Frame {
+            point,
+            tangent,
+            normal,
+            binormal: tangent.cross(normal)
+        };
+        self.prev = Some(frame);
+        Some(frame)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.points.size_hint()
+    }
+}
+
+impl<'a, F, B> ExactSizeIterator for FrameIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F, Point = Point3d<F>> {}
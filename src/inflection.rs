@@ -0,0 +1,161 @@
+use super::{Bez, BezCurve, Float, Point2d, Vector2d, total_cmp};
+use super::solve::{bernstein_eval, bernstein_mul, bernstein_roots};
+
+/// The classification of a 2D cubic curve, which is determined by the number of real inflections the
+/// curve has when `t` isn't bounded to `0.0` to `1.0`. As such, the feature a curve is classified by
+/// may lie outside of the part of the curve between its end points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubicClass {
+    /// The curve has two distinct inflections, or one if the other is at infinity.
+    Serpentine,
+    /// The curve's two inflections coincide, leaving a cusp where its slope is zero.
+    Cusp,
+    /// The curve has no real inflections, and crosses over itself instead.
+    Loop,
+    /// The curve has no inflections, and is a quadratic curve that has been elevated to a cubic.
+    Quadratic,
+    /// The curve's points all lie on the same line.
+    Line,
+    /// The curve's points are all the same point.
+    Point
+}
+
+/// Get the coefficients of the bernstein polynomial `x'(t) * y''(t) - y'(t) * x''(t)`, which is the
+/// numerator of the curve's signed curvature. It has the same sign as the curvature, so the curve's
+/// inflections are its roots.
+fn curvature_numerator<F, B>(curve: &B) -> Vec<F>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let d1 = curve.derivative();
+    let d2 = d1.derivative();
+
+    let d1x = d1.numerator(|v| v.x);
+    let d1y = d1.numerator(|v| v.y);
+    let d2x = d2.numerator(|v| v.x);
+    let d2y = d2.numerator(|v| v.y);
+
+    bernstein_mul(&d1x, &d2y).iter()
+        .zip(bernstein_mul(&d1y, &d2x))
+        .map(|(a, b)| *a - b)
+        .collect()
+}
+
+/// Get the `t`s at which the curve's slope is zero, in increasing order.
+///
+/// The slope is only zero where both of its axes are, so the candidates are the roots of each axis,
+/// which get kept if the entire slope is zero to within a tolerance relative to the slope's size.
+pub(crate) fn cusps<F, B>(curve: &B) -> Vec<F>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let d1 = curve.derivative();
+    let scale = d1.points().iter().fold(F::zero(), |acc, v| acc.max(v.len()));
+    // A curve that is a single point doesn't have any cusps, as it doesn't go anywhere.
+    if scale == F::zero() {
+        return Vec::new();
+    }
+    let tolerance = F::epsilon().sqrt();
+
+    let d1x = d1.numerator(|v| v.x);
+    let d1y = d1.numerator(|v| v.y);
+    let mut cusps: Vec<F> = bernstein_roots(&d1x).into_iter()
+        .chain(bernstein_roots(&d1y))
+        .filter(|t| d1.interp_unbounded(*t).len() <= scale * tolerance)
+        .collect();
+
+    // Both axes' roots get found at a cusp, each with its own rounding error.
+    cusps.sort_by(total_cmp);
+    cusps.dedup_by(|a, b| (*a - *b).abs() <= tolerance);
+    cusps
+}
+
+/// Get the `t`s strictly between `0.0` and `1.0` at which the curve's signed curvature changes sign, in
+/// increasing order.
+///
+/// The sign of the curvature is checked halfway between each root of its numerator and the roots on
+/// either side, which weeds out roots that the curvature only touches zero at without changing sign.
+pub(crate) fn inflections<F, B>(curve: &B) -> Vec<F>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let numerator = curvature_numerator(curve);
+    let roots = bernstein_roots(&numerator);
+    let cusps = cusps(curve);
+    let half = F::from_f32(0.5).unwrap();
+    let tolerance = F::epsilon().sqrt();
+
+    let mut inflections = Vec::new();
+    for (i, t) in roots.iter().cloned().enumerate() {
+        if t <= F::zero() || t >= F::one() || cusps.iter().any(|c| (*c - t).abs() <= tolerance) {
+            continue;
+        }
+
+        let before = if 0 == i { F::zero() } else { roots[i - 1] };
+        let after = roots.get(i + 1).cloned().unwrap_or_else(F::one);
+        let sign_before = bernstein_eval(&numerator, (before + t) * half) < F::zero();
+        let sign_after = bernstein_eval(&numerator, (t + after) * half) < F::zero();
+        if sign_before != sign_after {
+            inflections.push(t);
+        }
+    }
+
+    inflections
+}
+
+/// Classify a 2D cubic curve by the inflections it has with unbounded `t`. Returns `None` if the curve
+/// isn't a cubic, or if it's rational with weights that aren't all the same.
+pub(crate) fn classify<F, B>(curve: &B) -> Option<CubicClass>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    if curve.order() != 3 {
+        return None;
+    }
+
+    // Rational curves can only be elevated to polynomial curves when they're the same as one.
+    let cubic = curve.elevate_to(3)?;
+    Bez::from_slice_static(cubic.as_ref()).map(|cubic| classify_cubic(&cubic))
+}
+
+/// Classify the cubic by the inflections it has with unbounded `t`.
+///
+/// For a cubic, the numerator of the signed curvature is a quadratic polynomial, and the sign of its
+/// discriminant tells whether the curve has two inflections, a cusp, or a loop.
+fn classify_cubic<F: Float>(cubic: &Bez<F, Point2d<F>, 3>) -> CubicClass {
+    let d1: Bez<F, Vector2d<F>, 2> = cubic.derivative();
+    let d2: Bez<F, Vector2d<F>, 1> = d1.derivative();
+    let d1_scale = d1.as_ref().iter().fold(F::zero(), |acc, v| acc.max(v.len()));
+    let d2_scale = d2.as_ref().iter().fold(F::zero(), |acc, v| acc.max(v.len()));
+    if d1_scale == F::zero() {
+        return CubicClass::Point;
+    }
+
+    // Coefficients within this of zero are treated as zero, to absorb rounding errors.
+    let tolerance = F::epsilon().sqrt() * d1_scale * d2_scale;
+    let c = curvature_numerator(cubic);
+    if c.iter().all(|coeff| coeff.abs() <= tolerance) {
+        return CubicClass::Line;
+    }
+
+    // Convert the bernstein coefficients to the power basis, `a2 * t^2 + a1 * t + a0`. The cubic
+    // term is always zero, as the cross product cancels it out.
+    let three = F::from_f32(3.0).unwrap();
+    let two = F::from_f32(2.0).unwrap();
+    let a0 = c[0];
+    let a1 = (c[1] - c[0]) * three;
+    let a2 = (c[2] - c[1] * two + c[0]) * three;
+
+    if a2.abs() <= tolerance {
+        if a1.abs() <= tolerance {
+            CubicClass::Quadratic
+        } else {
+            CubicClass::Serpentine
+        }
+    } else {
+        let discriminant = a1 * a1 - a2 * a0 * two * two;
+        if discriminant.abs() <= tolerance * tolerance {
+            CubicClass::Cusp
+        } else if discriminant > F::zero() {
+            CubicClass::Serpentine
+        } else {
+            CubicClass::Loop
+        }
+    }
+}
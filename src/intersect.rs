@@ -0,0 +1,496 @@
+use super::{BezCurve, OwnedBezCurve, Float, MetricVector, Point2d, Vector2d, bounds, total_cmp};
+use super::solve::bernstein_roots;
+
+/// How far a `Line` extends past the two points that define it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    /// The line extends forever in both directions.
+    Infinite,
+    /// The line starts at `start` and extends forever in the direction of `end`.
+    Ray,
+    /// The line starts at `start` and ends at `end`.
+    Segment
+}
+
+/// A straight line in 2D, passing through `start` and `end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line<F: Float> {
+    pub start: Point2d<F>,
+    pub end: Point2d<F>,
+    pub kind: LineKind
+}
+
+impl<F: Float> Line<F> {
+    /// Create an infinite line passing through `start` and `end`.
+    pub fn infinite(start: Point2d<F>, end: Point2d<F>) -> Line<F> {
+        Line {
+            start,
+            end,
+            kind: LineKind::Infinite
+        }
+    }
+
+    /// Create a ray starting at `start` and passing through `end`.
+    pub fn ray(start: Point2d<F>, end: Point2d<F>) -> Line<F> {
+        Line {
+            start,
+            end,
+            kind: LineKind::Ray
+        }
+    }
+
+    /// Create a line segment between `start` and `end`.
+    pub fn segment(start: Point2d<F>, end: Point2d<F>) -> Line<F> {
+        Line {
+            start,
+            end,
+            kind: LineKind::Segment
+        }
+    }
+
+    /// Check whether the point at `line_t` along the line, where `0.0` is `start` and `1.0` is `end`,
+    /// is part of the line.
+    fn contains_t(&self, line_t: F) -> bool {
+        match self.kind {
+            LineKind::Infinite => true,
+            LineKind::Ray => F::zero() <= line_t,
+            LineKind::Segment => F::zero() <= line_t && line_t <= F::one()
+        }
+    }
+}
+
+/// A point at which a curve crosses a line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineIntersection<F: Float> {
+    /// The `t` of the intersection on the curve
+    pub t: F,
+    /// How far along the line the intersection is, where `0.0` is the line's `start` and `1.0` is its
+    /// `end`
+    pub line_t: F,
+    /// The point at which the curve and the line intersect
+    pub point: Point2d<F>
+}
+
+/// Find the points at which the curve crosses the line, in increasing order of `t`.
+///
+/// Rotating the curve so that the line lies along the x axis turns the problem into finding the roots
+/// of the curve's y axis. Only the y axis matters, so each control point's y coordinate is found
+/// directly from its cross product with the line's direction. That scales the coordinates by the
+/// length of the line, which doesn't move the roots.
+pub(crate) fn intersect_line<F, B>(curve: &B, line: &Line<F>) -> Vec<LineIntersection<F>>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let direction: Vector2d<F> = (line.end - line.start).into();
+    let direction_len_sq = direction.dot(direction);
+    // A line through a single point doesn't have a direction to rotate the curve onto.
+    if direction_len_sq == F::zero() {
+        return Vec::new();
+    }
+
+    let coeffs = curve.numerator(|p| direction.x * (p.y - line.start.y) - direction.y * (p.x - line.start.x));
+
+    bernstein_roots(&coeffs).into_iter()
+        .map(|t| {
+            let point = curve.interp_unbounded(t);
+            let offset: Vector2d<F> = (point - line.start).into();
+            LineIntersection {
+                t,
+                line_t: offset.dot(direction) / direction_len_sq,
+                point
+            }
+        })
+        .filter(|intersection| line.contains_t(intersection.line_t))
+        .collect()
+}
+
+/// The maximum number of times `intersect_curves` subdivides the curves. Curves that still aren't flat
+/// at this depth get approximated by their chords regardless.
+const MAX_DEPTH: u32 = 32;
+
+/// The smallest tolerance `intersect_curves` works to, as a fraction of the size of the curves. With a
+/// tolerance much smaller than this, or zero, the parts of the curves that overlap or touch never get
+/// flat enough and keep subdividing until `MAX_DEPTH`, which takes exponentially long. The chords'
+/// intersections get refined afterwards anyway, so this doesn't cost any precision.
+fn min_tolerance<F: Float>() -> F {
+    F::epsilon().sqrt()
+}
+
+/// Get the length of the diagonal of the curve's control box.
+fn box_size<F, B>(curve: &B) -> F
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let (min, max) = curve.control_box();
+    Vector2d::from(max - min).len()
+}
+
+/// The number of Newton iterations used to refine an intersection found between two chords.
+const REFINE_ITERATIONS: u32 = 8;
+
+/// The number of points between the ends of a potential overlap that get checked for lying on both
+/// curves.
+const OVERLAP_SAMPLES: usize = 16;
+
+/// The 2D cross product of two vectors, which is the z component of their 3D cross product.
+fn cross<F: Float>(a: Vector2d<F>, b: Vector2d<F>) -> F {
+    a.x * b.y - a.y * b.x
+}
+
+/// Get the distance from `point` to the segment between `start` and `end`.
+fn segment_distance<F: Float>(point: Point2d<F>, start: Point2d<F>, end: Point2d<F>) -> F {
+    let chord: Vector2d<F> = (end - start).into();
+    let offset: Vector2d<F> = (point - start).into();
+    let chord_len_sq = chord.dot(chord);
+    if chord_len_sq == F::zero() {
+        return offset.len();
+    }
+
+    let s = (offset.dot(chord) / chord_len_sq).max(F::zero()).min(F::one());
+    (offset - chord * s).len()
+}
+
+/// Check whether the curve lies within `tolerance` of its chord. As the curve lies within the convex
+/// hull of its control points, it's enough for every control point to be that close to the chord.
+fn is_flat<F, B>(curve: &B, tolerance: F) -> bool
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let points = curve.points();
+    let (start, end) = (points[0], points[points.len() - 1]);
+    points.iter().all(|p| segment_distance(*p, start, end) <= tolerance)
+}
+
+/// Check whether the control boxes of two curves come within `tolerance` of each other.
+fn boxes_overlap<F, A, B>(a: &A, b: &B, tolerance: F) -> bool
+        where F: Float,
+              A: BezCurve<F, Point = Point2d<F>>,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let (a_min, a_max) = a.control_box();
+    let (b_min, b_max) = b.control_box();
+    a_min.x <= b_max.x + tolerance && b_min.x <= a_max.x + tolerance &&
+    a_min.y <= b_max.y + tolerance && b_min.y <= a_max.y + tolerance
+}
+
+/// Find where the segments `a0`-`a1` and `b0`-`b1` meet, as the fractions of the way along each
+/// segment, pushing the results onto `contacts`. Parallel segments that lie within `tolerance` of each
+/// other push both ends of the part they share.
+fn intersect_chords<F: Float>(a0: Point2d<F>, a1: Point2d<F>, b0: Point2d<F>, b1: Point2d<F>, tolerance: F,
+                              contacts: &mut Vec<(F, F)>) {
+    let da: Vector2d<F> = (a1 - a0).into();
+    let db: Vector2d<F> = (b1 - b0).into();
+    let offset: Vector2d<F> = (b0 - a0).into();
+    let (len_a, len_b) = (da.len(), db.len());
+    let denom = cross(da, db);
+
+    if denom.abs() > F::epsilon() * len_a * len_b {
+        let s = cross(offset, db) / denom;
+        let u = cross(offset, da) / denom;
+        // Let the crossing lie slightly past the ends, so that crossings right at the point where
+        // the curves got split aren't missed by both halves.
+        let (slack_a, slack_b) = (tolerance / len_a, tolerance / len_b);
+        if -slack_a <= s && s <= F::one() + slack_a && -slack_b <= u && u <= F::one() + slack_b {
+            contacts.push((s.max(F::zero()).min(F::one()), u.max(F::zero()).min(F::one())));
+        }
+        return;
+    }
+
+    // The segments are parallel, so they only meet if they lie along the same line. Their shared part
+    // is found by projecting onto the longer of the two.
+    let swapped = len_b > len_a;
+    let (p0, dp, len_p, q0, q1, dq) = if swapped {
+        (b0, db, len_b, a0, a1, da)
+    } else {
+        (a0, da, len_a, b0, b1, db)
+    };
+    if len_p == F::zero() {
+        // Both segments are points.
+        if offset.len() <= tolerance {
+            contacts.push((F::zero(), F::zero()));
+        }
+        return;
+    }
+    let line_distance = |q: Point2d<F>| cross(dp, (q - p0).into()).abs() / len_p;
+    if line_distance(q0) > tolerance || line_distance(q1) > tolerance {
+        return;
+    }
+
+    let len_p_sq = len_p * len_p;
+    let project = |q: Point2d<F>| Vector2d::from(q - p0).dot(dp) / len_p_sq;
+    let (q0_s, q1_s) = (project(q0), project(q1));
+    let start = q0_s.min(q1_s).max(F::zero());
+    let end = q0_s.max(q1_s).min(F::one());
+    if start > end + tolerance / len_p {
+        return;
+    }
+
+    let len_q_sq = dq.dot(dq);
+    for s in &[start, end.max(start)] {
+        let q = if len_q_sq == F::zero() {
+            F::zero()
+        } else {
+            let point = p0 + dp * *s;
+            (Vector2d::from(point - q0).dot(dq) / len_q_sq).max(F::zero()).min(F::one())
+        };
+        contacts.push(if swapped { (q, *s) } else { (*s, q) });
+    }
+}
+
+/// Recursively subdivide the curves, which cover `a_range` and `b_range` of the original curves, until
+/// they're flat enough to be approximated by their chords. Contacts between the chords get pushed onto
+/// `contacts` as `t`s on the original curves.
+fn subdivide<F, A, B>(a: &A, a_range: (F, F), b: &B, b_range: (F, F), tolerance: F, depth: u32,
+                      contacts: &mut Vec<(F, F)>)
+        where F: Float,
+              A: OwnedBezCurve<F, Point = Point2d<F>>,
+              B: OwnedBezCurve<F, Point = Point2d<F>> {
+    if !boxes_overlap(a, b, tolerance) {
+        return;
+    }
+
+    let half = F::from_f32(0.5).unwrap();
+    let a_flat = depth >= MAX_DEPTH || is_flat(a, tolerance);
+    let b_flat = depth >= MAX_DEPTH || is_flat(b, tolerance);
+
+    if a_flat && b_flat {
+        let (a_points, b_points) = (a.points(), b.points());
+        let start = contacts.len();
+        intersect_chords(a_points[0], a_points[a_points.len() - 1], b_points[0], b_points[b_points.len() - 1],
+                         tolerance, contacts);
+        for contact in &mut contacts[start..] {
+            contact.0 = a_range.0 + (a_range.1 - a_range.0) * contact.0;
+            contact.1 = b_range.0 + (b_range.1 - b_range.0) * contact.1;
+        }
+    } else if a_flat {
+        let b_mid = (b_range.0 + b_range.1) * half;
+        let (b_left, b_right) = b.split_unbounded(half);
+        subdivide(a, a_range, &b_left, (b_range.0, b_mid), tolerance, depth + 1, contacts);
+        subdivide(a, a_range, &b_right, (b_mid, b_range.1), tolerance, depth + 1, contacts);
+    } else if b_flat {
+        let a_mid = (a_range.0 + a_range.1) * half;
+        let (a_left, a_right) = a.split_unbounded(half);
+        subdivide(&a_left, (a_range.0, a_mid), b, b_range, tolerance, depth + 1, contacts);
+        subdivide(&a_right, (a_mid, a_range.1), b, b_range, tolerance, depth + 1, contacts);
+    } else {
+        let a_mid = (a_range.0 + a_range.1) * half;
+        let b_mid = (b_range.0 + b_range.1) * half;
+        let (a_left, a_right) = a.split_unbounded(half);
+        let (b_left, b_right) = b.split_unbounded(half);
+        subdivide(&a_left, (a_range.0, a_mid), &b_left, (b_range.0, b_mid), tolerance, depth + 1, contacts);
+        subdivide(&a_left, (a_range.0, a_mid), &b_right, (b_mid, b_range.1), tolerance, depth + 1, contacts);
+        subdivide(&a_right, (a_mid, a_range.1), &b_left, (b_range.0, b_mid), tolerance, depth + 1, contacts);
+        subdivide(&a_right, (a_mid, a_range.1), &b_right, (b_mid, b_range.1), tolerance, depth + 1, contacts);
+    }
+}
+
+/// Refine an intersection with Newton's method on `a(t) - b(u) = 0`. The chords the intersection was
+/// found on only approximate the curves, so this gets the intersection the rest of the way there.
+/// Returns the original pair if refinement doesn't bring the curves any closer together, such as at
+/// tangential intersections.
+fn refine<F, A, B>(a: &A, b: &B, pair: (F, F)) -> (F, F)
+        where F: Float,
+              A: BezCurve<F, Point = Point2d<F>>,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let distance = |(t, u): (F, F)| Vector2d::from(a.interp_unbounded(t) - b.interp_unbounded(u)).len();
+    let (mut t, mut u) = pair;
+
+    for _ in 0..REFINE_ITERATIONS {
+        let residual: Vector2d<F> = (a.interp_unbounded(t) - b.interp_unbounded(u)).into();
+        let (da, db) = (a.slope_unbounded(t), b.slope_unbounded(u));
+        // Solve `da * dt - db * du = -residual` with Cramer's rule.
+        let det = cross(da, db);
+        if det == F::zero() {
+            break;
+        }
+        t = (t + cross(db, residual) / det).max(F::zero()).min(F::one());
+        u = (u + cross(da, residual) / det).max(F::zero()).min(F::one());
+    }
+
+    if distance((t, u)) <= distance(pair) {
+        (t, u)
+    } else {
+        pair
+    }
+}
+
+/// Find the part of the curves where they overlap, to within `tolerance`, returning the pairs of `t`s
+/// at the two ends of the overlap in increasing order of `a`'s `t`s.
+///
+/// Distinct polynomial curves can't overlap, so an overlap can only end where one of the curves does.
+/// Each curve's ends get projected onto the other curve, and the ends that lie on the other curve are
+/// the candidates for the ends of the overlap. The widest pair of candidates that the curves stay
+/// together between is the overlap.
+fn overlap<F, A, B>(a: &A, b: &B, tolerance: F) -> Option<((F, F), (F, F))>
+        where F: Float,
+              A: BezCurve<F, Point = Point2d<F>>,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let two = F::from_f32(2.0).unwrap();
+    let mut ends = Vec::with_capacity(4);
+    for t in &[F::zero(), F::one()] {
+        let (u, distance) = b.project(a.interp_unbounded(*t));
+        if distance <= tolerance {
+            ends.push((*t, u));
+        }
+    }
+    for u in &[F::zero(), F::one()] {
+        let (t, distance) = a.project(b.interp_unbounded(*u));
+        if distance <= tolerance {
+            ends.push((t, *u));
+        }
+    }
+    ends.sort_by(|x, y| total_cmp(&x.0, &y.0));
+
+    let samples = F::from_usize(OVERLAP_SAMPLES + 1).unwrap();
+    let together = |first: (F, F), last: (F, F)| (1..OVERLAP_SAMPLES + 1)
+        .map(|i| first.0 + (last.0 - first.0) * F::from_usize(i).unwrap() / samples)
+        .all(|t| b.project(a.interp_unbounded(t)).1 <= tolerance * two);
+
+    let mut widest: Option<((F, F), (F, F))> = None;
+    for (i, first) in ends.iter().enumerate() {
+        for last in &ends[i + 1..] {
+            let extent = Vector2d::from(a.interp_unbounded(last.0) - a.interp_unbounded(first.0)).len();
+            let wider = widest.map_or(true, |(w_first, w_last)| last.0 - first.0 > w_last.0 - w_first.0);
+            if extent > tolerance * two && wider && together(*first, *last) {
+                widest = Some((*first, *last));
+            }
+        }
+    }
+
+    widest
+}
+
+/// Find the pairs of `t`s at which the two curves meet, to within `tolerance`, in increasing order of
+/// `a`'s `t`s.
+///
+/// Both curves get subdivided until they are flat enough to treat as line segments, and the segments
+/// get intersected. Pieces of the curves whose control boxes don't overlap are thrown out along the
+/// way.
+///
+/// The intersections found on neighbouring pieces get grouped together wherever the curves stay within
+/// `tolerance` of each other in between them. Each group is a single contact between the curves, and
+/// the pair in it where the curves are closest is returned. The exception is where the curves overlap,
+/// which gets found separately by `overlap`: that returns both ends of the overlap instead, and replaces
+/// any contacts found within it.
+pub(crate) fn intersect_curves<F, A, B>(a: &A, b: &B, tolerance: F) -> Vec<(F, F)>
+        where F: Float,
+              A: OwnedBezCurve<F, Point = Point2d<F>>,
+              B: OwnedBezCurve<F, Point = Point2d<F>> {
+    let size = box_size(a).max(box_size(b));
+    let tolerance = tolerance.max(size * min_tolerance());
+
+    let mut contacts = Vec::new();
+    subdivide(a, (F::zero(), F::one()), b, (F::zero(), F::one()), tolerance, 0, &mut contacts);
+    contacts.sort_by(|x, y| total_cmp(&x.0, &y.0).then(total_cmp(&x.1, &y.1)));
+
+    let half = F::from_f32(0.5).unwrap();
+    let two = F::from_f32(2.0).unwrap();
+    let distance = |(t, u): (F, F)| Vector2d::from(a.interp_unbounded(t) - b.interp_unbounded(u)).len();
+
+    let mut intersections = Vec::new();
+    if let Some((first, last)) = overlap(a, b, tolerance) {
+        let ends = [a.interp_unbounded(first.0), a.interp_unbounded(last.0)];
+        contacts.retain(|&(t, _)| {
+            let point = a.interp_unbounded(t);
+            (t < first.0 || last.0 < t) && ends.iter().all(|end| Vector2d::from(point - *end).len() > tolerance * two)
+        });
+        intersections.push(first);
+        intersections.push(last);
+    }
+
+    let mut i = 0;
+    while i < contacts.len() {
+        // Extend the group for as long as the curves stay together between neighbouring contacts.
+        let mut j = i;
+        while j + 1 < contacts.len() {
+            let (prev, next) = (contacts[j], contacts[j + 1]);
+            let mid = ((prev.0 + next.0) * half, (prev.1 + next.1) * half);
+            if distance(mid) > tolerance * two {
+                break;
+            }
+            j += 1;
+        }
+
+        let closest = contacts[i..j + 1].iter()
+            .map(|pair| refine(a, b, *pair))
+            .fold(contacts[i], |closest, pair| if distance(pair) < distance(closest) { pair } else { closest });
+        intersections.push(closest);
+
+        i = j + 1;
+    }
+
+    intersections.sort_by(|x, y| total_cmp(&x.0, &y.0));
+    intersections
+}
+
+/// A piece of a curve that is monotonic along both axes, which means it can't intersect itself.
+pub(crate) struct Piece<F: Float, B> {
+    curve: B,
+    /// The index of the curve the piece came from, within its chain
+    index: usize,
+    /// The `t`s at which the piece starts and ends on the curve it came from
+    range: (F, F)
+}
+
+/// Split the curve into pieces at its extrema, tagging each piece with the curve's `index`.
+pub(crate) fn monotone_pieces<F, B>(curve: &B, index: usize) -> Vec<Piece<F, B>>
+        where F: Float,
+              B: OwnedBezCurve<F, Point = Point2d<F>> {
+    let mut ts: Vec<F> = bounds::extrema(curve).into_iter()
+        .filter(|t| F::zero() < *t && *t < F::one())
+        .collect();
+    let curves = bounds::split_at(curve, &ts);
+    ts.insert(0, F::zero());
+    ts.push(F::one());
+
+    curves.into_iter()
+        .zip(ts.windows(2))
+        .map(|(curve, range)| Piece {
+            curve,
+            index,
+            range: (range[0], range[1])
+        })
+        .collect()
+}
+
+/// Find the places where the sequence of pieces crosses itself, to within `tolerance`, as pairs of
+/// curve indices and `t`s. Each pair is in order along the sequence, and the pairs are sorted by their
+/// first halves.
+///
+/// Every piece gets intersected with every later piece. Neighbouring pieces always meet where they
+/// join, so contacts at the joins are skipped. If `closed` is true, the last piece is treated as
+/// joining back up with the first.
+pub(crate) fn self_intersect<F, B>(pieces: &[Piece<F, B>], closed: bool, tolerance: F) -> Vec<((usize, F), (usize, F))>
+        where F: Float,
+              B: OwnedBezCurve<F, Point = Point2d<F>> {
+    let size = pieces.iter().fold(F::zero(), |size, piece| size.max(box_size(&piece.curve)));
+    let tolerance = tolerance.max(size * min_tolerance());
+    let two = F::from_f32(2.0).unwrap();
+    let to_curve = |piece: &Piece<F, B>, t: F| piece.range.0 + (piece.range.1 - piece.range.0) * t;
+
+    let mut crossings = Vec::new();
+    for (i, a) in pieces.iter().enumerate() {
+        for (j, b) in pieces.iter().enumerate().skip(i + 1) {
+            let mut joins = Vec::new();
+            if j == i + 1 {
+                joins.push(a.curve.interp_unbounded(F::one()));
+            }
+            if closed && 0 == i && j == pieces.len() - 1 {
+                joins.push(a.curve.interp_unbounded(F::zero()));
+            }
+
+            for (ta, tb) in intersect_curves(&a.curve, &b.curve, tolerance) {
+                let point = a.curve.interp_unbounded(ta);
+                if joins.iter().any(|join| Vector2d::from(point - *join).len() <= tolerance * two) {
+                    continue;
+                }
+                crossings.push((((a.index, to_curve(a, ta)), (b.index, to_curve(b, tb))), point));
+            }
+        }
+    }
+
+    // Crossings right where a curve got split into pieces get found on the pieces on both sides.
+    crossings.sort_by(|x, y| {
+        let ((x_index, x_t), _) = x.0;
+        let ((y_index, y_t), _) = y.0;
+        x_index.cmp(&y_index).then(total_cmp(&x_t, &y_t))
+    });
+    crossings.dedup_by(|x, y| Vector2d::from(x.1 - y.1).len() <= tolerance * two);
+    crossings.into_iter().map(|(pair, _)| pair).collect()
+}
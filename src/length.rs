@@ -0,0 +1,299 @@
+use super::{BezCurve, Float, Point, MetricVector, total_cmp};
+
+/// The abscissae of the five-point Gauss-Legendre quadrature rule, on the interval `[-1, 1]`.
+const GAUSS_ABSCISSAE: [f64; 5] = [
+    0.0,
+    -0.5384693101056831,
+    0.5384693101056831,
+    -0.906179845938664,
+    0.906179845938664
+];
+
+/// The weights of the five-point Gauss-Legendre quadrature rule, corresponding to the values in
+/// `GAUSS_ABSCISSAE`.
+const GAUSS_WEIGHTS: [f64; 5] = [
+    0.5688888888888889,
+    0.4786286704993665,
+    0.4786286704993665,
+    0.2369268850561891,
+    0.2369268850561891
+];
+
+/// The maximum number of times an interval gets subdivided before its estimate is accepted regardless
+/// of the error. Stops the subdivision from recursing forever around cusps, where the speed isn't
+/// smooth enough for the quadrature rule to converge quickly.
+const MAX_DEPTH: u32 = 24;
+
+/// The smallest tolerance `arc_length` works to, as a multiple of the machine epsilon times the length
+/// being measured.
+const MIN_TOLERANCE: f32 = 64.0;
+
+/// Estimate the length of the curve between `t0` and `t1` with a single application of the
+/// quadrature rule to the curve's speed.
+fn gauss_legendre<F, B>(curve: &B, t0: F, t1: F) -> F
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    let half = F::from_f32(0.5).unwrap();
+    let center = (t0 + t1) * half;
+    let radius = (t1 - t0) * half;
+
+    let mut acc = F::zero();
+    for (x, w) in GAUSS_ABSCISSAE.iter().zip(GAUSS_WEIGHTS.iter()) {
+        let t = center + radius * F::from_f64(*x).unwrap();
+        acc = acc + curve.slope_unbounded(t).length() * F::from_f64(*w).unwrap();
+    }
+    acc * radius
+}
+
+/// Adaptively integrate the curve's speed between `t0` and `t1`, given an estimate of the length over
+/// that interval. The interval gets split in half until the estimate of the whole agrees with the sum
+/// of the halves' estimates to within `tolerance`.
+fn adaptive_length<F, B>(curve: &B, t0: F, t1: F, estimate: F, tolerance: F, depth: u32) -> F
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    let half = F::from_f32(0.5).unwrap();
+    let mid = (t0 + t1) * half;
+    let left = gauss_legendre(curve, t0, mid);
+    let right = gauss_legendre(curve, mid, t1);
+
+    if depth >= MAX_DEPTH || (left + right - estimate).abs() <= tolerance {
+        left + right
+    } else {
+        adaptive_length(curve, t0, mid, left, tolerance * half, depth + 1) +
+        adaptive_length(curve, mid, t1, right, tolerance * half, depth + 1)
+    }
+}
+
+/// Get the length of the curve between `t0` and `t1`, to within roughly `tolerance`.
+///
+/// Rounding error keeps the estimates from agreeing much more closely than `MIN_TOLERANCE` times the
+/// length, so smaller tolerances, including zero and negative ones, get raised to that. Otherwise every
+/// interval would get subdivided down to `MAX_DEPTH`.
+pub(crate) fn arc_length<F, B>(curve: &B, t0: F, t1: F, tolerance: F) -> F
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    let estimate = gauss_legendre(curve, t0, t1);
+    let min_tolerance = estimate.abs() * F::epsilon() * F::from_f32(MIN_TOLERANCE).unwrap();
+    adaptive_length(curve, t0, t1, estimate, tolerance.max(min_tolerance), 0)
+}
+
+/// A table of the curve's length at evenly-spaced values of `t`, used to map lengths along the curve
+/// back to `t` without having to integrate over the whole curve for every lookup. The table borrows the
+/// curve it was built for, as lookups refine the table's estimate with the curve itself.
+pub struct LengthTable<'a, F, B>
+        where F: Float,
+              B: 'a + BezCurve<F> {
+    curve: &'a B,
+    /// The length of the curve between `0.0` and `i / (lengths.len() - 1)`, for each index `i`.
+    lengths: Vec<F>,
+    tolerance: F
+}
+
+impl<'a, F, B> Clone for LengthTable<'a, F, B>
+        where F: Float,
+              B: BezCurve<F> {
+    fn clone(&self) -> LengthTable<'a, F, B> {
+        LengthTable {
+            curve: self.curve,
+            lengths: self.lengths.clone(),
+            tolerance: self.tolerance
+        }
+    }
+}
+
+impl<'a, F, B> LengthTable<'a, F, B>
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    /// Build a length table for the given curve, with lengths accurate to within roughly `tolerance`.
+    pub fn new(curve: &'a B, tolerance: F) -> LengthTable<'a, F, B> {
+        // Higher-order curves can wiggle more, so they get more entries in the table.
+        let segments = (curve.order() * 8).max(8);
+        let segments_f = F::from_usize(segments).unwrap();
+        let segment_tolerance = tolerance / segments_f;
+
+        let mut lengths = Vec::with_capacity(segments + 1);
+        let mut acc = F::zero();
+        lengths.push(acc);
+        for i in 0..segments {
+            let t0 = F::from_usize(i).unwrap() / segments_f;
+            let t1 = F::from_usize(i + 1).unwrap() / segments_f;
+            acc = acc + arc_length(curve, t0, t1, segment_tolerance);
+            lengths.push(acc);
+        }
+
+        LengthTable {
+            curve,
+            lengths,
+            tolerance
+        }
+    }
+
+    /// Get the curve the table was built for.
+    #[inline]
+    pub fn curve(&self) -> &'a B {
+        self.curve
+    }
+
+    /// Get the total length of the curve the table was built for.
+    #[inline]
+    pub fn total(&self) -> F {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Get the `t` at which the curve the table was built for reaches the given length. `length` is
+    /// bounded on `0.0` to `total()` inclusive, give or take the table's tolerance; returns `None` if
+    /// it is not within bounds.
+    ///
+    /// The table narrows the search down to one of its segments, and Newton's method finds `t` within
+    /// that segment.
+    pub fn t_at_length(&self, length: F) -> Option<F> {
+        // Lengths measured some other way, such as with `BezCurve::arc_length`, may be slightly off
+        // from the table's, so anything within the tolerance of the ends gets accepted.
+        if !(-self.tolerance <= length && length <= self.total() + self.tolerance) {
+            return None;
+        }
+        let length = length.max(F::zero()).min(self.total());
+
+        let segments = self.lengths.len() - 1;
+        let segments_f = F::from_usize(segments).unwrap();
+
+        // The index of the segment that contains `length`
+        let segment = match self.lengths.binary_search_by(|l| total_cmp(l, &length)) {
+            Ok(index) => return Some(F::from_usize(index).unwrap() / segments_f),
+            Err(index) => index - 1
+        };
+
+        let start_len = self.lengths[segment];
+        let end_len = self.lengths[segment + 1];
+        let mut lower = F::from_usize(segment).unwrap() / segments_f;
+        let mut upper = F::from_usize(segment + 1).unwrap() / segments_f;
+        let segment_start = lower;
+
+        // Start off by assuming the curve has a constant speed over the segment.
+        let mut t = lower + (upper - lower) * (length - start_len) / (end_len - start_len);
+        for _ in 0..32 {
+            let error = start_len + arc_length(self.curve, segment_start, t, self.tolerance) - length;
+            if error.abs() <= self.tolerance {
+                break;
+            }
+
+            // Keep track of the interval the answer is known to be in, so that we can fall back to
+            // bisection whenever Newton's method would step outside of it.
+            if error > F::zero() {
+                upper = t;
+            } else {
+                lower = t;
+            }
+
+            let speed = self.curve.slope_unbounded(t).length();
+            let next = t - error / speed;
+            t = if lower < next && next < upper {
+                next
+            } else {
+                (lower + upper) * F::from_f32(0.5).unwrap()
+            };
+        }
+
+        Some(t)
+    }
+}
+
+/// An iterator over points on a bezier curve that are evenly spaced along the curve's length, as
+/// opposed to `InterpIter`'s points which are evenly spaced in `t`.
+pub struct UniformInterpIter<'a, F, B>
+        where F: Float,
+              B: 'a + BezCurve<F> {
+    /// The curve's length table, which the curve gets borrowed through.
+    table: LengthTable<'a, F, B>,
+    /// Like `InterpIter`, the distance along the curve is stored as an integer that gets divided by
+    /// `samples` and multiplied by the curve's length. Initialized to `0`, or `1` if there are no samples.
+    len_nodiv: u32,
+    /// Like `len_nodiv`, but used as the upper bound for next_back. Initialized to `samples`'s integer
+    /// representation.
+    len_back_nodiv: u32,
+    /// The number of samples we are taking from the curve.
+    samples: F
+}
+
+impl<'a, F, B> UniformInterpIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    /// Create an iterator that splits the curve into the given number of samples, with the distances
+    /// between the samples accurate to within roughly `tolerance`. Like `InterpIter`, an iterator with
+    /// zero samples doesn't have any points.
+    pub fn new(curve: &'a B, samples: u32, tolerance: F) -> UniformInterpIter<'a, F, B> {
+        UniformInterpIter {
+            table: LengthTable::new(curve, tolerance),
+            // Starting past the upper bound leaves the iterator empty, as there's no distance to space
+            // zero samples by.
+            len_nodiv: if 0 == samples { 1 } else { 0 },
+            len_back_nodiv: samples,
+            samples: F::from_u32(samples).unwrap()
+        }
+    }
+
+    /// Get the point that is `len_nodiv / samples` of the way along the curve.
+    fn point(&self, len_nodiv: u32) -> Option<B::Point> {
+        let length = self.table.total() * F::from_u32(len_nodiv).unwrap() / self.samples;
+        let t = self.table.t_at_length(length)?;
+        Some(self.table.curve.interp_unbounded(t))
+    }
+}
+
+impl<'a, F, B> Iterator for UniformInterpIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    type Item = B::Point;
+    fn next(&mut self) -> Option<B::Point> {
+        if self.len_nodiv <= self.len_back_nodiv {
+            let point = self.point(self.len_nodiv);
+            self.len_nodiv += 1;
+            point
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = if self.len_back_nodiv < self.len_nodiv {
+            0
+        } else {
+            self.len_back_nodiv - self.len_nodiv + 1
+        } as usize;
+
+        (size, Some(size))
+    }
+}
+
+impl<'a, F, B> DoubleEndedIterator for UniformInterpIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    fn next_back(&mut self) -> Option<B::Point> {
+        if self.len_nodiv <= self.len_back_nodiv {
+            let point = self.point(self.len_back_nodiv);
+
+            // See `InterpIter::next_back` for why this check is needed.
+            if 0 == self.len_back_nodiv {
+                self.len_nodiv = 1;
+            } else {
+                self.len_back_nodiv -= 1;
+            }
+
+            point
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, F, B> ExactSizeIterator for UniformInterpIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {}
@@ -1,5 +1,5 @@
 //! A crate that provides generic bezier curves of varying order and dimensionality, including numerous
-//! stack-allocated curves and an (admittedly not entirely functional) n-order curve.
+//! stack-allocated curves and a heap-allocated n-order curve.
 
 extern crate num_traits;
 
@@ -9,22 +9,105 @@ mod macros;
 mod markers;
 pub use markers::*;
 
+mod bez;
+pub use bez::*;
+
 mod nbez;
 pub use nbez::*;
 
+mod length;
+pub use length::{LengthTable, UniformInterpIter};
+
+mod bounds;
+mod conic;
+mod differential;
+mod elevate;
+mod frame;
+pub use frame::{Frame, FrameIter};
+mod inflection;
+mod intersect;
+pub use intersect::{Line, LineKind, LineIntersection};
+pub use inflection::CubicClass;
+mod project;
+mod rational;
+pub use rational::{Homogeneous, RationalCurve, RBez, RNBez};
+mod reduce;
+mod solve;
+mod spline;
+pub use spline::{BSpline, Nurbs};
+mod transform;
+pub use transform::{Transform, ProjectiveTransform, Transform2d, Transform3d, Projective2d, Projective3d};
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
+use std::ops::{Add, Sub, Mul, Div};
+
+use num_traits::identities::Zero;
+
+/// The operations needed to linearly interpolate between two values. Implemented for all points and
+/// vectors, as well as plain floats.
+trait Lerp<F>: Add<Output = Self> + Mul<F, Output = Self> + Copy {}
+impl<F, T> Lerp<F> for T where T: Add<Output = T> + Mul<F, Output = T> + Copy {}
+
+/// The operations needed to take arbitrary linear combinations of values, like `Lerp` but with
+/// subtraction, division and a zero value as well. Implemented for all points and vectors, as well as
+/// plain floats, which lets a rational curve's weights go through the same code as its points.
+trait Linear<F>: Lerp<F> + Sub<Output = Self> + Div<F, Output = Self> + Zero {}
+impl<F, T> Linear<F> for T where T: Lerp<F> + Sub<Output = T> + Div<F, Output = T> + Zero {}
 
 #[inline] 
-fn lerp<PV: PVOps<F>, F: Float>(a: PV, b: PV, factor: F) -> PV { 
+fn lerp<PV: Lerp<F>, F: Float>(a: PV, b: PV, factor: F) -> PV { 
     let fact1 = F::from_f32(1.0).unwrap() - factor; 
     a * fact1 + b * factor 
 }
 
-// There are macros in place to make it easier to create new bezier structs, as they can be created
-// with a very consistent pattern. However, those macros are also written in a very consistent pattern
-// which unfortunately is significantly harder, if not impossible, to create with a traditional
-// macro. So, the macro invocations are generated with the build script and then inserted here.
+/// Order two floats like `f64::total_cmp`, which `Float` doesn't provide. NaNs sort after every other
+/// value, so sorting a list of roots that contains one doesn't panic.
+fn total_cmp<F: Float>(a: &F, b: &F) -> Ordering {
+    a.partial_cmp(b).unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+/// Evaluate the curve with the given points at `t` with de Casteljau's algorithm. `points` is used as
+/// scratch space, so its contents are overwritten.
+fn de_casteljau<PV: Lerp<F>, F: Float>(points: &mut [PV], t: F) -> PV {
+    let len = points.len();
+    for i in 1..len {
+        for j in 0..len - i {
+            points[j] = lerp(points[j], points[j + 1], t);
+        }
+    }
+    points[0]
+}
+
+/// Split the curve with the given points at `t` with de Casteljau's algorithm, writing the points of
+/// the two new curves to `left` and `right`. `lerps` is used as scratch space. All four slices must
+/// have the same length.
+fn de_casteljau_split<PV: Lerp<F>, F: Float>(points: &[PV], t: F, lerps: &mut [PV], left: &mut [PV], right: &mut [PV]) {
+    let order = points.len() - 1;
+
+    // Each pass replaces the first `len - 1` live elements of `lerps` with interpolations of adjacent
+    // elements, so after pass `i` the first element is the `i`th point of the left curve and the last
+    // live element is the `i`th point from the end of the right curve.
+    lerps.copy_from_slice(points);
+    left[0] = lerps[0];
+    right[order] = lerps[order];
+    for i in 1..order + 1 {
+        for j in 0..order + 1 - i {
+            lerps[j] = lerp(lerps[j], lerps[j + 1], t);
+        }
+
+        left[i] = lerps[0];
+        right[order - i] = lerps[order - i];
+    }
+}
+
+// There are macros in place to make it easier to create new point types, `Bez` aliases and `BezCurve`
+// implementations for `Bez`, as they can be created with a very consistent pattern. However, those macros are also
+// invoked in a very consistent pattern which unfortunately is significantly harder, if not impossible,
+// to create with a traditional macro. So, the macro invocations are generated with the build script
+// and then inserted here.
 include!(concat!(env!("OUT_DIR"), "/macro_invocs.rs"));
 
 impl<F: Float> Vector2d<F> {
@@ -40,7 +123,7 @@ impl<F: Float> Vector2d<F> {
 /// Iterator over bezier curve chains
 pub struct BezIter<'a, F, B>
         where F: Float,
-              B: BezCurve<F> + OrderStatic {
+              B: OwnedBezCurve<F> + OrderStatic {
     points: *const B::Point,
     len: usize,
     lifetime: PhantomData<&'a ()>
@@ -48,7 +131,7 @@ pub struct BezIter<'a, F, B>
 
 impl<'a, F, B> Iterator for BezIter<'a, F, B>
         where F: Float,
-              B: BezCurve<F> + OrderStatic {
+              B: OwnedBezCurve<F> + OrderStatic {
     type Item = B;
     fn next(&mut self) -> Option<B> {
         use std::slice;
@@ -74,7 +157,7 @@ impl<'a, F, B> Iterator for BezIter<'a, F, B>
 
 impl<'a, F, B> DoubleEndedIterator for BezIter<'a, F, B>
         where F: Float,
-              B: BezCurve<F> + OrderStatic {
+              B: OwnedBezCurve<F> + OrderStatic {
     fn next_back(&mut self) -> Option<B> {
         use std::slice;
 
@@ -95,7 +178,7 @@ impl<'a, F, B> DoubleEndedIterator for BezIter<'a, F, B>
 
 impl<'a, F, B> ExactSizeIterator for BezIter<'a, F, B> 
         where F: Float,
-              B: BezCurve<F> + OrderStatic {}
+              B: OwnedBezCurve<F> + OrderStatic {}
 
 /// An iterator over a bezier curve's interpolated points
 pub struct InterpIter<'a, F, B>
@@ -166,14 +249,18 @@ impl<'a, F, B> ExactSizeIterator for InterpIter<'a, F, B>
               B: BezCurve<F> {}
 
 /// Bezier curve trait
-pub trait BezCurve<F: Float>: AsRef<[<Self as BezCurve<F>>::Point]> + AsMut<[<Self as BezCurve<F>>::Point]>
+pub trait BezCurve<F: Float>
         where Self: Sized {
     type Point: Point<F>;
-    type Elevated: BezCurve<F, Point = Self::Point>;
+    type Elevated: OwnedBezCurve<F, Point = Self::Point>;
+    /// The curve's derivative, which is one order lower and made out of the point's vectors
+    type Derivative: OwnedBezCurve<F, Point = <Self::Point as Point<F>>::Vector>;
+    /// The curve one order lower, as returned by `reduce`
+    type Reduced: OwnedBezCurve<F, Point = Self::Point>;
 
-    /// Attempt to create a curve from a slice. Fails if the slice's length does not match the
-    /// curve's order + 1.
-    fn from_slice(&[Self::Point]) -> Option<Self>;
+    /// Get the curve's control points. Polynomial curves borrow the points they're made out of, while
+    /// rational curves project theirs down from homogeneous coordinates.
+    fn points(&self) -> Cow<'_, [Self::Point]>;
 
     /// Perform interpolation on the curve for the given `t`, bounded on `0.0` to `1.0` inclusive. 
     /// Returns `None` if `t` is not within bounds.
@@ -193,20 +280,133 @@ pub trait BezCurve<F: Float>: AsRef<[<Self as BezCurve<F>>::Point]> + AsMut<[<Se
     /// Get the slope for the given `t` with no range bounds
     fn slope_unbounded(&self, t: F) -> <Self::Point as Point<F>>::Vector;
 
+    /// Get the second derivative for the given `t`, bounded on `0.0` to `1.0` inclusive. Returns `None`
+    /// if `t` is not within bounds.
+    fn second_derivative(&self, t: F) -> Option<<Self::Point as Point<F>>::Vector> {
+        check_t_bounds!(t);
+        Some(self.second_derivative_unbounded(t))
+    }
+    /// Get the second derivative for the given `t` with no range bounds
+    fn second_derivative_unbounded(&self, t: F) -> <Self::Point as Point<F>>::Vector {
+        let points = self.points();
+        let order = points.len() - 1;
+        if order < 2 {
+            return Self::Point::zero().into();
+        }
+
+        // The second derivative's points are the differences between adjacent points of the first
+        // derivative, scaled by the two orders.
+        let mut ddpoints: Vec<Self::Point> = points.windows(3)
+            .map(|p| (p[2] - p[1]) - (p[1] - p[0]))
+            .collect();
+        (de_casteljau(&mut ddpoints, t) * F::from_usize(order * (order - 1)).unwrap()).into()
+    }
+
+    /// Get the curvature of the curve for the given `t`, bounded on `0.0` to `1.0` inclusive. Returns
+    /// `None` if `t` is not within bounds, or if the curve's slope is zero at `t`, such as at a cusp,
+    /// as the curvature isn't defined there.
+    ///
+    /// The curvature is the reciprocal of the radius of curvature, and is always positive. For the
+    /// direction the curve is turning in on 2D curves, use `signed_curvature`.
+    fn curvature(&self, t: F) -> Option<F>
+            where <Self::Point as Point<F>>::Vector: MetricVector<F> {
+        check_t_bounds!(t);
+        differential::curvature(self, t)
+    }
+
+    /// Get the radius of the circle that best fits the curve at the given `t`, bounded on `0.0` to `1.0`
+    /// inclusive. Returns `None` if `t` is not within bounds or the curvature isn't defined. Straight
+    /// parts of the curve have an infinite radius.
+    fn radius_of_curvature(&self, t: F) -> Option<F>
+            where <Self::Point as Point<F>>::Vector: MetricVector<F> {
+        self.curvature(t).map(|curvature| curvature.recip())
+    }
+
     /// Elevate the curve order, getting a curve that is one order higher but gives the same results
     /// upon interpolation
     fn elevate(&self) -> Self::Elevated;
 
-    /// Split the curve at the given `t`, bounded on `0.0` to `1.0` inclusive. Returns `None` if `t` is
-    /// not within bounds.
-    fn split(&self, t: F) -> Option<(Self, Self)> {
-        check_t_bounds!(t);
-        Some(self.split_unbounded(t))
+    /// Elevate the curve to the given order in a single step, getting a heap-allocated curve that
+    /// gives the same results upon interpolation. Returns `None` if `order` is lower than the curve's
+    /// order, or if the curve is rational with weights that aren't all the same, as a polynomial curve
+    /// can't represent it.
+    fn elevate_to(&self, order: usize) -> Option<NBez<F, Self::Point>> {
+        if order < self.order() {
+            None
+        } else {
+            Some(NBez::from_container(elevate::elevate_points(&self.points(), order)))
+        }
+    }
+
+    /// Elevate this curve and `other` to whichever of their orders is higher, so that their control
+    /// points can be compared or blended one-to-one. Returns `None` if either curve is rational with
+    /// weights that aren't all the same, as `elevate_to` can't elevate it.
+    fn elevate_common<C>(&self, other: &C) -> Option<[NBez<F, Self::Point>; 2]>
+            where C: BezCurve<F, Point = Self::Point> {
+        let order = self.order().max(other.order());
+        Some([self.elevate_to(order)?, other.elevate_to(order)?])
+    }
+
+    /// Get the derivative of the curve as a curve of its own, one order lower than this one. Its
+    /// points are the differences between adjacent points, scaled by the order, and interpolating it
+    /// gives the same results as `slope`.
+    fn derivative(&self) -> Self::Derivative;
+
+    /// Reduce the curve order, getting the curve one order lower that best fits this one along with an
+    /// upper bound on the distance between the two curves. Curves of order `0` are returned as-is.
+    ///
+    /// The reduced curve keeps this curve's end points, and minimizes the squared distance between the
+    /// curves over the rest of its length. Reducing a curve that was created with `elevate` gives back
+    /// the original curve.
+    ///
+    /// Rational curves get fit in homogeneous coordinates instead, where they're ordinary curves. The
+    /// distance between a rational curve and its reduction can't be bounded the same way, so for them
+    /// the returned distance is an estimate found by sampling both curves.
+    fn reduce(&self) -> (Self::Reduced, F)
+            where <Self::Point as Point<F>>::Vector: MetricVector<F> {
+        let original = self.points();
+        let points = reduce::reduce_points(&original, self.order().saturating_sub(1));
+        let error = reduce::error_bound(&original, &points);
+        (Self::Reduced::from_slice(&points).unwrap(), error)
+    }
+
+    /// Transform the curve with a projective transform. Projective transforms can't be represented
+    /// exactly by polynomial curves, so this returns a rational curve, whose points get transformed in
+    /// homogeneous coordinates along with their weights.
+    fn transform_projective<T>(&self, transform: &T) -> RNBez<F, Self::Point>
+            where Self::Point: Homogeneous<F>,
+                  T: ProjectiveTransform<F, Self::Point> {
+        let lifted: Vec<_> = self.points().iter()
+            .map(|point| transform.apply_homogeneous(point.lift(F::one())))
+            .collect();
+        RNBez::from_homogeneous(&lifted)
+    }
+
+    /// Get the coefficients of a bernstein polynomial with the same roots and signs as `value` along the
+    /// curve, where `value` is linear in the curve's points. For polynomial curves, they're the values
+    /// of the curve's points. Rational curves give the values of their numerator instead, which has the
+    /// same roots and signs as the curve itself as long as the weights are positive.
+    fn numerator<V, G>(&self, value: G) -> Vec<V>
+            where V: Copy + Add<Output = V> + Sub<Output = V> + Mul<F, Output = V> + Div<F, Output = V> + Zero,
+                  G: Fn(Self::Point) -> V {
+        self.points().iter().map(|point| value(*point)).collect()
+    }
+
+    /// Get the coefficients of a bernstein polynomial with the same roots and signs as the derivative
+    /// of `value` along the curve, where `value` is linear in the curve's points. For polynomial
+    /// curves, they're the differences between the values of adjacent points. Rational curves give the
+    /// numerator of the derivative instead. A curve of order `0` has a single zero coefficient.
+    fn derivative_numerator<V, G>(&self, value: G) -> Vec<V>
+            where V: Copy + Add<Output = V> + Sub<Output = V> + Mul<F, Output = V> + Div<F, Output = V> + Zero,
+                  G: Fn(Self::Point) -> V {
+        let values = self.numerator(value);
+        if values.len() < 2 {
+            return vec![V::zero()];
+        }
+
+        values.windows(2).map(|pair| pair[1] - pair[0]).collect()
     }
 
-    /// Split the curve with no range bounds
-    fn split_unbounded(&self, t: F) -> (Self, Self);
-    
     /// Gets the order of the curve
     fn order(&self) -> usize;
 
@@ -220,8 +420,291 @@ pub trait BezCurve<F: Float>: AsRef<[<Self as BezCurve<F>>::Point]> + AsMut<[<Se
             samples: F::from_u32(samples).unwrap()
         }
     }
+
+    /// Get the length of the curve, to within roughly `tolerance`. Tolerances below what rounding error
+    /// allows, such as `0.0`, get raised to that, giving the most precise length that can be measured.
+    fn arc_length(&self, tolerance: F) -> F
+            where <Self::Point as Point<F>>::Vector: MetricVector<F> {
+        length::arc_length(self, F::zero(), F::one(), tolerance)
+    }
+
+    /// Get the length of the curve between `0.0` and the given `t`, to within roughly `tolerance`. `t`
+    /// is bounded on `0.0` to `1.0` inclusive; returns `None` if `t` is not within bounds.
+    fn arc_length_to(&self, t: F, tolerance: F) -> Option<F>
+            where <Self::Point as Point<F>>::Vector: MetricVector<F> {
+        check_t_bounds!(t);
+        Some(length::arc_length(self, F::zero(), t, tolerance))
+    }
+
+    /// Get the `t` at which the length of the curve between `0.0` and `t` equals `length`, to within
+    /// roughly `tolerance`. `length` is bounded on `0.0` to the curve's length inclusive; returns `None`
+    /// if it is not within bounds.
+    ///
+    /// This builds a new `LengthTable` on every call, so use one directly when looking up multiple
+    /// lengths on the same curve.
+    fn t_at_length(&self, length: F, tolerance: F) -> Option<F>
+            where <Self::Point as Point<F>>::Vector: MetricVector<F> {
+        LengthTable::new(self, tolerance).t_at_length(length)
+    }
+
+    /// Get an iterator over points that are evenly spaced along the length of this curve, splitting
+    /// the curve into the given number of samples. The spacing is accurate to within roughly
+    /// `tolerance`.
+    fn uniform_interp_iter<'a>(&'a self, samples: u32, tolerance: F) -> UniformInterpIter<'a, F, Self>
+            where <Self::Point as Point<F>>::Vector: MetricVector<F> {
+        UniformInterpIter::new(self, samples, tolerance)
+    }
+
+    /// Find the point on the curve closest to `point`, returning the `t` of that point and its distance
+    /// from `point`. `t` is bounded on `0.0` to `1.0` inclusive.
+    fn project(&self, point: Self::Point) -> (F, F)
+            where <Self::Point as Point<F>>::Vector: MetricVector<F> {
+        project::project(self, point)
+    }
+
+    /// Get the smallest axis-aligned box that contains the curve between `0.0` and `1.0`, as the box's
+    /// minimum and maximum corners.
+    fn bounding_box(&self) -> (Self::Point, Self::Point)
+            where Self::Point: AsRef<[F]> + AsMut<[F]> {
+        bounds::bounding_box(self)
+    }
+
+    /// Get the `t`s at which the given axis of the curve reaches a local minimum or maximum, in increasing
+    /// order. Only extrema between `0.0` and `1.0` inclusive are returned.
+    fn axis_extrema(&self, axis: usize) -> Vec<F>
+            where Self::Point: AsRef<[F]> {
+        bounds::axis_extrema(self, axis)
+    }
+
+    /// Get the `t`s at which any axis of the curve reaches a local minimum or maximum, in increasing
+    /// order. Only extrema between `0.0` and `1.0` inclusive are returned.
+    fn extrema(&self) -> Vec<F>
+            where Self::Point: AsRef<[F]> {
+        bounds::extrema(self)
+    }
+
+    /// Get the smallest axis-aligned box that contains all of the curve's control points, as the box's
+    /// minimum and maximum corners. The curve is always contained within this box, but it's usually
+    /// larger than the `bounding_box`. However, it's much cheaper to calculate.
+    fn control_box(&self) -> (Self::Point, Self::Point)
+            where Self::Point: AsRef<[F]> + AsMut<[F]> {
+        bounds::control_box(self)
+    }
+}
+
+/// A bezier curve that owns its points, so that new curves of the same type can be created. This adds
+/// the functions that build curves of the curve's own type, such as `split`. Curves that borrow their
+/// points, like an `NBez` backed by a `&mut [P]`, are only `BezCurve`s.
+pub trait OwnedBezCurve<F: Float>: BezCurve<F> + Clone {
+    /// Attempt to create a curve from a slice. Fails if the slice's length does not match the
+    /// curve's order + 1.
+    fn from_slice(&[Self::Point]) -> Option<Self>;
+
+    /// Split the curve at the given `t`, bounded on `0.0` to `1.0` inclusive. Returns `None` if `t` is
+    /// not within bounds.
+    fn split(&self, t: F) -> Option<(Self, Self)> {
+        check_t_bounds!(t);
+        Some(self.split_unbounded(t))
+    }
+
+    /// Split the curve with no range bounds
+    fn split_unbounded(&self, t: F) -> (Self, Self);
+
+    /// Get the part of the curve between `t0` and `t1`, reparameterized so that it starts at `t0` and
+    /// ends at `t1`. If `t0` is greater than `t1`, the part is reversed. Both `t`s are bounded on `0.0`
+    /// to `1.0` inclusive; returns `None` if either is not within bounds.
+    fn subcurve(&self, t0: F, t1: F) -> Option<Self> {
+        check_t_bounds!(t0);
+        check_t_bounds!(t1);
+        Some(self.subcurve_unbounded(t0, t1))
+    }
+
+    /// Get the part of the curve between `t0` and `t1` with no range bounds.
+    fn subcurve_unbounded(&self, t0: F, t1: F) -> Self {
+        let (low, high) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+
+        // Cut off the end of the curve first, and then the start. Once the end is gone, `low` has to get
+        // rescaled onto what's left.
+        let sub = if high != F::zero() {
+            let (left, _) = self.split_unbounded(high);
+            left.split_unbounded(low / high).1
+        } else {
+            let (_, right) = self.split_unbounded(low);
+            right.split_unbounded((high - low) / (F::one() - low)).0
+        };
+
+        if t0 <= t1 {
+            sub
+        } else {
+            sub.reverse()
+        }
+    }
+
+    /// Get the curve with each of its points transformed by `transform`. For affine transforms, this is
+    /// exactly the transformed curve. Rational curves keep their weights, and their points at infinity
+    /// only get the linear part of the transform, as they're directions rather than positions.
+    fn transform<T>(&self, transform: &T) -> Self
+            where T: Transform<F, Self::Point> {
+        let points: Vec<Self::Point> = self.points().iter().map(|point| transform.apply(*point)).collect();
+        Self::from_slice(&points).unwrap()
+    }
+
+    /// Get the curve with its points in reverse order, which traces the same path in the opposite
+    /// direction.
+    fn reverse(&self) -> Self {
+        let mut points = self.points().into_owned();
+        points.reverse();
+        Self::from_slice(&points).unwrap()
+    }
+    
+    /// Split the curve at its extrema, getting curves that are monotonic along every axis. For 2D
+    /// curves, those are curves that are both x- and y-monotone.
+    fn split_monotone(&self) -> Vec<Self>
+            where Self::Point: AsRef<[F]> {
+        bounds::split_monotone(self)
+    }
+}
+
+/// Functions on one-dimensional curves, whose points are plain floats. Implemented for every
+/// `BezCurve` with `F` as its point type.
+pub trait Curve1d<F: Float>: BezCurve<F, Point = F> {
+    /// Get every `t` on `0.0` to `1.0` inclusive at which a one-dimensional curve is zero, in increasing
+    /// order. A curve that is zero everywhere has no isolated roots, so it returns an empty vector.
+    ///
+    /// The roots are isolated with the variation diminishing property: the polynomial has no more roots
+    /// in an interval than its control points have sign changes. Intervals with more than one sign
+    /// change get subdivided, and intervals with exactly one get their root found iteratively. Roots
+    /// that are too close together to be told apart get reported as a single root.
+    fn roots(&self) -> Vec<F> {
+        solve::bernstein_roots(&self.numerator(|value| value))
+    }
+}
+
+impl<F: Float, B: BezCurve<F, Point = F>> Curve1d<F> for B {}
+
+/// Functions on 2D curves. Implemented for every `BezCurve` with `Point2d` as its point type.
+pub trait Curve2d<F: Float>: BezCurve<F, Point = Point2d<F>> {
+    /// Get the signed curvature of a 2D curve for the given `t`, bounded on `0.0` to `1.0` inclusive.
+    /// Returns `None` if `t` is not within bounds or the curve's slope is zero at `t`. The curvature is
+    /// positive when the curve turns counterclockwise, and negative when it turns clockwise.
+    fn signed_curvature(&self, t: F) -> Option<F> {
+        check_t_bounds!(t);
+        differential::signed_curvature(self, t)
+    }
+
+    /// Get the `t`s at which a 2D curve's signed curvature changes sign, in increasing order. Only
+    /// inflections strictly between `0.0` and `1.0` are found.
+    ///
+    /// Cusps aren't counted as inflections, and are found with `cusps` instead. Cubics can also be
+    /// checked for loops with `classify`.
+    fn inflections(&self) -> Vec<F> {
+        inflection::inflections(self)
+    }
+
+    /// Classify a 2D cubic curve by the inflections it has with unbounded `t`. Returns `None` if the
+    /// curve isn't a cubic, or if it's rational with weights that aren't all the same.
+    fn classify(&self) -> Option<CubicClass> {
+        inflection::classify(self)
+    }
+
+    /// Get the `t`s on `0.0` to `1.0` inclusive at which a 2D curve's slope is zero, in increasing
+    /// order. The curve can come to a sharp point or turn back on itself at these `t`s.
+    fn cusps(&self) -> Vec<F> {
+        inflection::cusps(self)
+    }
+
+    /// Find the points on `0.0` to `1.0` inclusive at which a 2D curve crosses or touches `line`, in
+    /// increasing order of `t`. Parts of the curve that lie along the line aren't reported.
+    fn intersect_line(&self, line: &Line<F>) -> Vec<LineIntersection<F>> {
+        intersect::intersect_line(self, line)
+    }
+
+    /// Find the pairs of `t`s at which a 2D curve meets `other`, to within `tolerance`, with each
+    /// pair's first `t` being on this curve and the second on `other`. The pairs are in increasing
+    /// order of this curve's `t`s.
+    ///
+    /// Places where the curves touch without crossing are returned as a single pair. Where the curves
+    /// overlap, the pairs at both ends of the overlapping part are returned. `tolerance` is raised to a
+    /// small fraction of the curves' size if it's below that, so `0.0` asks for the most precise result.
+    fn intersections<C>(&self, other: &C, tolerance: F) -> Vec<(F, F)>
+            where Self: OwnedBezCurve<F>,
+                  C: OwnedBezCurve<F, Point = Point2d<F>> {
+        intersect::intersect_curves(self, other, tolerance)
+    }
+
+    /// Find the pairs of `t`s at which a 2D curve crosses over itself, to within `tolerance`. The first
+    /// `t` of each pair is smaller than the second, and the pairs are in increasing order of their first
+    /// `t`s. A curve whose ends meet isn't counted as crossing itself there.
+    ///
+    /// Only cubics and higher can cross themselves, so lower orders always return an empty vector.
+    fn self_intersections(&self, tolerance: F) -> Vec<(F, F)>
+            where Self: OwnedBezCurve<F> {
+        if self.order() < 3 {
+            return Vec::new();
+        }
+
+        let start = self.interp_unbounded(F::zero());
+        let end = self.interp_unbounded(F::one());
+        let closed = Vector2d::from(end - start).len() <= tolerance;
+        intersect::self_intersect(&intersect::monotone_pieces(self, 0), closed, tolerance).into_iter()
+            .map(|((_, t0), (_, t1))| (t0, t1))
+            .collect()
+    }
+}
+
+impl<F: Float, B: BezCurve<F, Point = Point2d<F>>> Curve2d<F> for B {}
+
+/// Functions on 3D curves, for finding the frames that follow the curve through space. Implemented for
+/// every `BezCurve` with `Point3d` as its point type.
+pub trait Curve3d<F: Float>: BezCurve<F, Point = Point3d<F>> {
+    /// Get the unit tangent of a 3D curve for the given `t`, bounded on `0.0` to `1.0` inclusive.
+    /// Returns `None` if `t` is not within bounds, or if the curve's slope is zero at `t`.
+    fn tangent(&self, t: F) -> Option<Vector3d<F>> {
+        check_t_bounds!(t);
+        frame::tangent(self, t)
+    }
+
+    /// Get the unit normal of a 3D curve for the given `t`, bounded on `0.0` to `1.0` inclusive. The
+    /// normal points towards the center of the curve's turn. Returns `None` if `t` is not within
+    /// bounds, or if the curve is straight at `t`.
+    fn normal(&self, t: F) -> Option<Vector3d<F>> {
+        self.frenet_frame(t).map(|frame| frame.normal)
+    }
+
+    /// Get the unit binormal of a 3D curve for the given `t`, bounded on `0.0` to `1.0` inclusive. The
+    /// binormal is perpendicular to the plane the curve is turning in. Returns `None` if `t` is not
+    /// within bounds, or if the curve is straight at `t`.
+    fn binormal(&self, t: F) -> Option<Vector3d<F>> {
+        check_t_bounds!(t);
+        frame::binormal(self, t)
+    }
+
+    /// Get the torsion of a 3D curve for the given `t`, bounded on `0.0` to `1.0` inclusive. Returns
+    /// `None` if `t` is not within bounds, or if the curve is straight at `t`.
+    fn torsion(&self, t: F) -> Option<F> {
+        check_t_bounds!(t);
+        frame::torsion(self, t)
+    }
+
+    /// Get the Frenet frame of a 3D curve for the given `t`, bounded on `0.0` to `1.0` inclusive.
+    /// Returns `None` if `t` is not within bounds, or if the curve is straight at `t`.
+    ///
+    /// The Frenet frame flips over at inflections and spins with the curve's torsion. For sweeping
+    /// shapes along the curve, `rotation_minimizing_frames` is usually a better fit.
+    fn frenet_frame(&self, t: F) -> Option<Frame<F>> {
+        check_t_bounds!(t);
+        frame::frenet_frame(self, t)
+    }
+
+    /// Get an iterator over rotation-minimizing frames on a 3D curve, sampled at the same `t`s as
+    /// `interp_iter`.
+    fn rotation_minimizing_frames<'a>(&'a self, samples: u32) -> FrameIter<'a, F, Self> {
+        FrameIter::new(self, samples)
+    }
 }
 
+impl<F: Float, B: BezCurve<F, Point = Point3d<F>>> Curve3d<F> for B {}
+
 /// Trait to mark curves that have order known at compiletime.
 pub trait OrderStatic {
     /// Gets the compiletime-known curve order.
@@ -233,7 +716,7 @@ pub trait OrderStatic {
 #[derive(Clone, Copy)]
 pub struct BezChain<F, B, C>
         where F: Float,
-              B: BezCurve<F> + OrderStatic,
+              B: OwnedBezCurve<F> + OrderStatic,
               C: AsRef<[B::Point]> {
     points: C,
     phantom: PhantomData<(F, B)>
@@ -241,7 +724,7 @@ pub struct BezChain<F, B, C>
 
 impl<F, B, C> BezChain<F, B, C>
         where F: Float,
-              B: BezCurve<F> + OrderStatic,
+              B: OwnedBezCurve<F> + OrderStatic,
               C: AsRef<[B::Point]> {
     /// Create a new BezChain by wrapping around a container.
     #[inline]
@@ -288,11 +771,95 @@ impl<F, B, C> BezChain<F, B, C>
     pub fn unwrap(self) -> C {
         self.points
     }
+
+    /// Get the total length of all curves in the chain, to within roughly `tolerance`.
+    pub fn arc_length(&self, tolerance: F) -> F
+            where <B::Point as Point<F>>::Vector: MetricVector<F> {
+        // Split the tolerance between the curves, so that the error of the sum stays within it.
+        let tolerance = tolerance / F::from_usize(self.iter().len().max(1)).unwrap();
+        self.iter().fold(F::zero(), |acc, curve| acc + curve.arc_length(tolerance))
+    }
+
+    /// Find the point on the chain closest to `point`, returning the index of the curve that point is
+    /// on, the `t` of that point on the curve, and its distance from `point`. Returns `None` if the
+    /// chain doesn't contain any curves.
+    pub fn project(&self, point: B::Point) -> Option<(usize, F, F)>
+            where <B::Point as Point<F>>::Vector: MetricVector<F> {
+        self.iter()
+            .map(|curve| curve.project(point))
+            .enumerate()
+            .fold(None, |closest, (index, (t, distance))| match closest {
+                Some((_, _, closest_distance)) if closest_distance <= distance => closest,
+                _ => Some((index, t, distance))
+            })
+    }
+
+    /// Find the points at which the chain crosses or touches `line`, in order along the chain, along
+    /// with the index of the curve each point is on. A point where two curves join is only reported
+    /// once, on the first of the two curves.
+    pub fn intersect_line(&self, line: &Line<F>) -> Vec<(usize, LineIntersection<F>)>
+            where B: BezCurve<F, Point = Point2d<F>> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(index, curve)| curve.intersect_line(line).into_iter()
+                .filter(move |intersection| 0 == index || intersection.t != F::zero())
+                .map(move |intersection| (index, intersection)))
+            .collect()
+    }
+
+    /// Find the places where the chain crosses over itself, to within `tolerance`, as pairs of curve
+    /// indices and `t`s on those curves. This covers both curves that cross themselves and crossings
+    /// between different curves. The first half of each pair comes before the second along the chain,
+    /// and the pairs are in order of their first halves.
+    ///
+    /// Neighbouring curves aren't counted as crossing where they join, and neither are the first and
+    /// last curves if the chain is closed.
+    pub fn self_intersections(&self, tolerance: F) -> Vec<((usize, F), (usize, F))>
+            where B: BezCurve<F, Point = Point2d<F>> {
+        let end = match self.iter().next_back() {
+            Some(curve) => curve.interp_unbounded(F::one()),
+            None => return Vec::new()
+        };
+        let start = self.points.as_ref()[0];
+        let pieces: Vec<_> = self.iter()
+            .enumerate()
+            .flat_map(|(index, curve)| intersect::monotone_pieces(&curve, index))
+            .collect();
+
+        let closed = Vector2d::from(end - start).len() <= tolerance;
+        intersect::self_intersect(&pieces, closed, tolerance)
+    }
+
+    /// Get a copy of the chain with each of its points transformed by `transform`. For affine
+    /// transforms, each curve in the new chain is exactly the transformed curve.
+    pub fn transform<T>(&self, transform: &T) -> BezChain<F, B, Vec<B::Point>>
+            where T: Transform<F, B::Point> {
+        let points = self.points.as_ref().iter()
+            .map(|point| transform.apply(*point))
+            .collect();
+        BezChain::from_container(points)
+    }
+
+    /// Get the smallest axis-aligned box that contains every curve in the chain, as the box's minimum
+    /// and maximum corners. Returns `None` if the chain doesn't contain any curves.
+    pub fn bounding_box(&self) -> Option<(B::Point, B::Point)>
+            where B::Point: AsRef<[F]> + AsMut<[F]> {
+        self.iter()
+            .map(|curve| curve.bounding_box())
+            .fold(None, |chain_box, (min, max)| match chain_box {
+                Some((mut chain_min, mut chain_max)) => {
+                    bounds::include_point(&mut chain_min, &mut chain_max, &min);
+                    bounds::include_point(&mut chain_min, &mut chain_max, &max);
+                    Some((chain_min, chain_max))
+                }
+                None => Some((min, max))
+            })
+    }
 }
 
 impl<F, B, C> OrderStatic for BezChain<F, B, C>
         where F: Float,
-              B: BezCurve<F> + OrderStatic,
+              B: OwnedBezCurve<F> + OrderStatic,
               C: AsRef<[B::Point]> {
     #[inline]
     fn order_static() -> usize {
@@ -302,7 +869,7 @@ impl<F, B, C> OrderStatic for BezChain<F, B, C>
 
 impl<F, B, C> AsRef<C> for BezChain<F, B, C>
         where F: Float,
-              B: BezCurve<F> + OrderStatic,
+              B: OwnedBezCurve<F> + OrderStatic,
               C: AsRef<[B::Point]> {
     fn as_ref(&self) -> &C {
         &self.points
@@ -311,7 +878,7 @@ impl<F, B, C> AsRef<C> for BezChain<F, B, C>
 
 impl<F, B, C> AsMut<C> for BezChain<F, B, C>
         where F: Float,
-              B: BezCurve<F> + OrderStatic,
+              B: OwnedBezCurve<F> + OrderStatic,
               C: AsRef<[B::Point]> {
     fn as_mut(&mut self) -> &mut C {
         &mut self.points
@@ -320,7 +887,7 @@ impl<F, B, C> AsMut<C> for BezChain<F, B, C>
 
 impl<F, B, C> Debug for BezChain<F, B, C>
         where F: Float,
-              B: BezCurve<F> + OrderStatic,
+              B: OwnedBezCurve<F> + OrderStatic,
               C: AsRef<[B::Point]> + Debug {
     fn fmt(&self, f: &mut Formatter) -> Result<(), ::std::fmt::Error> {
         f.debug_tuple("BezChain")
@@ -410,6 +977,27 @@ mod tests {
         test_bez_elevation(&Bez6o::new(0.0, 1.0, -1.0, 2.0, -2.0, 3.0, -3.0));
     }
 
+    #[test]
+    fn bez_high_order() {
+        let bez6o = Bez6o::new(0.0, 1.0, -1.0, 2.0, -2.0, 3.0, -3.0);
+        let bez7o: NBez<f64, f64, [f64; 8]> = bez6o.elevate();
+        let bez8o: NBez<f64, f64> = bez7o.elevate();
+
+        test_poly_eq(&bez6o, &bez8o);
+        test_poly_slope_eq(&bez6o, &bez8o);
+        test_bez_elevation(&bez8o);
+        test_bez_split(&bez8o);
+
+        let nbez_poly: NBez<f64, f64> = NBez::from_slice(bez8o.as_ref()).unwrap();
+        test_poly_eq(&nbez_poly, &bez8o);
+        test_poly_slope_eq(&nbez_poly, &bez8o);
+
+        // Orders without an alias aren't `BezCurve`s, but their points can be evaluated as an `NBez`.
+        let bez40o: Bez<f64, f64, 40> = Bez::from_slice_static(&nbez_poly.elevate_to(40).unwrap().unwrap()).unwrap();
+        let nbez40o: NBez<f64, f64, [f64; 41]> = NBez::from_slice(bez40o.as_ref()).unwrap();
+        test_poly_eq(&bez6o, &nbez40o);
+    }
+
     #[test]
     fn nbez_elevation() {
         let mut nbez_poly = NBez::from_container(Vec::with_capacity(7));
@@ -435,7 +1023,7 @@ mod tests {
     }
 
     fn test_bez_split<B>(curve: &B)
-            where B: BezCurve<f64, Point = f64> {
+            where B: OwnedBezCurve<f64, Point = f64> {
 
         // Sanity check bool to make sure the assertions are actually running
         let mut has_run = false;
@@ -488,6 +1076,1055 @@ mod tests {
         test_bez_split(&bez6o);
     }
 
+    #[test]
+    fn nbez_split() {
+        let mut nbez_poly = NBez::from_container(Vec::with_capacity(7));
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(0.0);
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(1.0);
+
+        test_bez_split(&nbez_poly);
+
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(-1.0);
+        test_bez_split(&nbez_poly);
+        
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(2.0);
+        test_bez_split(&nbez_poly);
+        
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(-2.0);
+        test_bez_split(&nbez_poly);
+        
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(3.0);
+        test_bez_split(&nbez_poly);
+        
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(-3.0);
+        test_bez_split(&nbez_poly);
+    }
+
+    #[test]
+    fn nbez_borrowed() {
+        // A curve over a borrowed buffer can't create new curves of its own type, but everything else
+        // works on it.
+        let bez3o = Bez3o::new(0.0, 1.0, -1.0, 2.0);
+        let mut points = [0.0, 1.0, -1.0, 2.0];
+        let borrowed: NBez<f64, f64, &mut [f64]> = NBez::from_container(&mut points[..]);
+        test_poly_eq(&borrowed, &bez3o);
+        test_poly_slope_eq(&borrowed, &bez3o);
+        test_poly_eq(&borrowed.elevate(), &bez3o);
+        assert_eq!(bez3o.roots(), borrowed.roots());
+    }
+
+    #[test]
+    fn nbez_high_order() {
+        let bez3o = Bez3o::new(0.0, 1.0, -1.0, 2.0);
+        let mut nbez_poly: NBez<f64, f64> = NBez::from_slice(bez3o.as_ref()).unwrap();
+        while nbez_poly.order() < 60 {
+            nbez_poly = nbez_poly.elevate();
+        }
+
+        test_poly_eq(&nbez_poly, &bez3o);
+        test_poly_slope_eq(&nbez_poly, &bez3o);
+        test_bez_split(&nbez_poly);
+
+        // Evenly spaced points on a line interpolate `t` at any order, even past the point where the
+        // binomial factors overflow.
+        let points: Vec<f32> = (0..200).map(|k| k as f32 / 199.0).collect();
+        let nbez_f32: NBez<f32, f32> = NBez::from_container(points);
+        assert!((nbez_f32.interp(0.3).unwrap() - 0.3).abs() < 1e-5);
+        assert!((nbez_f32.slope(0.7).unwrap() - 1.0).abs() < 1e-3);
+
+        let points: Vec<f64> = (0..1200).map(|k| k as f64 / 1199.0).collect();
+        let nbez_f64: NBez<f64, f64> = NBez::from_container(points);
+        assert!((nbez_f64.interp(0.3).unwrap() - 0.3).abs() < 1e-12);
+        assert!((nbez_f64.slope(0.7).unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nbez_send_sync() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let nbez_poly: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0)]);
+        assert_send_sync(&nbez_poly);
+
+        let point = ::std::thread::spawn(move || nbez_poly.interp(0.5)).join().unwrap();
+        assert_eq!(Some(Point2d::new(0.5, 0.5)), point);
+    }
+
+    #[test]
+    fn nbez_from_slice() {
+        let points = [0.0, 1.0, -1.0, 2.0];
+        let bez3o = Bez3o::new(0.0, 1.0, -1.0, 2.0);
+
+        let nbez_vec: NBez<f64, f64, Vec<f64>> = NBez::from_slice(&points).unwrap();
+        test_poly_eq(&nbez_vec, &bez3o);
+
+        let nbez_array: NBez<f64, f64, [f64; 4]> = NBez::from_slice(&points).unwrap();
+        test_poly_eq(&nbez_array, &bez3o);
+
+        assert!(NBez::<f64, f64, [f64; 3]>::from_slice(&points).is_none());
+        assert!(NBez::<f64, f64, Vec<f64>>::from_slice(&[]).is_none());
+    }
+
+    #[test]
+    fn nbez_chain() {
+        let points = [0.0, 1.0, -1.0, 2.0, -2.0, 3.0, -3.0, 4.0];
+
+        let bez_chain: BezChain<f64, Bez3o<f64, f64>, _> = BezChain::from_container(&points[..]);
+        let array_chain: BezChain<f64, NBez<f64, f64, [f64; 4]>, _> = BezChain::from_container(&points[..]);
+        let nbez_chain = NBezChain::from_container(&points[..], 3);
+        assert_eq!(2, nbez_chain.iter().len());
+
+        for ((bez, array), nbez) in bez_chain.iter().zip(array_chain.iter()).zip(nbez_chain.iter()) {
+            test_poly_eq(&bez, &array);
+            test_poly_eq(&bez, &nbez);
+        }
+        for (bez, nbez) in bez_chain.iter().rev().zip(nbez_chain.iter().rev()) {
+            test_poly_eq(&bez, &nbez);
+        }
+        test_poly_eq(&bez_chain.get(1).unwrap(), &nbez_chain.get(1).unwrap());
+        assert!(nbez_chain.get(2).is_none());
+
+        let var_chain = NBezVarChain::from_containers(&points[..], [2, 4, 3]);
+        let bez2o = Bez2o::new(0.0, 1.0, -1.0);
+        let bez4o = Bez4o::new(-1.0, 2.0, -2.0, 3.0, -3.0);
+        assert_eq!(2, var_chain.iter().len());
+
+        let mut iter = var_chain.iter();
+        test_poly_eq(&iter.next().unwrap(), &bez2o);
+        test_poly_eq(&iter.next().unwrap(), &bez4o);
+        assert!(iter.next().is_none());
+
+        let mut iter_back = var_chain.iter();
+        test_poly_eq(&iter_back.next_back().unwrap(), &bez4o);
+        test_poly_eq(&iter_back.next_back().unwrap(), &bez2o);
+        assert!(iter_back.next_back().is_none());
+
+        test_poly_eq(&var_chain.get(1).unwrap(), &bez4o);
+        assert!(var_chain.get(2).is_none());
+    }
+
+    /// Approximate the length of a curve by summing the lengths of a very fine polyline.
+    fn polyline_length<B>(curve: &B) -> f64
+            where B: BezCurve<f64, Point = Point2d<f64>> {
+        let mut last = curve.interp(0.0).unwrap();
+        curve.interp_iter(100000).fold(0.0, |acc, point| {
+            let len = Vector2d::from(point - last).len();
+            last = point;
+            acc + len
+        })
+    }
+
+    #[test]
+    fn arc_length() {
+        let line = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 2.0), Point2d::new(3.0, 3.0));
+        assert!((line.arc_length(0.0000001) - 18.0f64.sqrt()).abs() <= 0.000001);
+
+        let curve = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(-1.0, 2.0), Point2d::new(0.5, -0.5));
+        let length = curve.arc_length(0.0000001);
+        assert!((length - polyline_length(&curve)).abs() <= 0.000001);
+        assert!((length - curve.arc_length_to(1.0, 0.0000001).unwrap()).abs() <= 0.000001);
+        assert_eq!(None, curve.arc_length_to(1.5, 0.0000001));
+
+        let (left, right) = curve.split(0.3).unwrap();
+        assert!((left.arc_length(0.0000001) - curve.arc_length_to(0.3, 0.0000001).unwrap()).abs() <= 0.000001);
+
+        let nbez: NBez<f64> = NBez::from_slice(curve.as_ref()).unwrap();
+        assert!((length - nbez.arc_length(0.0000001)).abs() <= 0.000001);
+
+        let chain: BezChain<f64, Bez3o<f64>, _> = BezChain::from_container([left[0], left[1], left[2], right[0], right[1], right[2], right[3]]);
+        assert!((length - chain.arc_length(0.0000001)).abs() <= 0.000001);
+
+        // A zero tolerance gets raised instead of subdividing forever, even around a cusp.
+        assert!((length - curve.arc_length(0.0)).abs() <= 0.000001);
+        let cusp: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0), Point2d::new(1.0, 0.0));
+        assert_eq!(1, cusp.cusps().len());
+        assert!((cusp.arc_length(0.0) - cusp.arc_length(0.0000001)).abs() <= 0.000001);
+    }
+
+    #[test]
+    fn t_at_length() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(-1.0, 2.0), Point2d::new(0.5, -0.5));
+        let length = curve.arc_length(0.0000001);
+        let table = LengthTable::new(&curve, 0.0000001);
+        assert!((length - table.total()).abs() <= 0.000001);
+
+        let mut t: f64 = 0.0;
+        while t <= 1.0 {
+            let t_length = curve.arc_length_to(t, 0.0000001).unwrap();
+            assert!((t - curve.t_at_length(t_length, 0.0000001).unwrap()).abs() <= 0.000001);
+            assert!((t - table.t_at_length(t_length).unwrap()).abs() <= 0.000001);
+            t += 1.0/30.0;
+        }
+
+        assert_eq!(Some(0.0), table.t_at_length(0.0));
+        assert_eq!(Some(1.0), table.t_at_length(table.total()));
+        assert_eq!(None, table.t_at_length(-0.5));
+        assert_eq!(None, table.t_at_length(table.total() + 0.5));
+    }
+
+    #[test]
+    fn uniform_interp_iter() {
+        const SAMPLES: u32 = 30;
+        let curve = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(-1.0, 2.0), Point2d::new(0.5, -0.5));
+        let spacing = curve.arc_length(0.0000001) / SAMPLES as f64;
+
+        let points: Vec<_> = curve.uniform_interp_iter(SAMPLES, 0.0000001).collect();
+        assert_eq!(SAMPLES as usize + 1, points.len());
+        assert_eq!(curve[0], points[0]);
+        assert_eq!(curve[3], points[SAMPLES as usize]);
+
+        for (i, point) in points.iter().enumerate() {
+            let t = curve.t_at_length(spacing * i as f64, 0.0000001).unwrap();
+            assert!(Vector2d::from(*point - curve.interp(t).unwrap()).len() <= 0.000001);
+        }
+
+        let points_back: Vec<_> = curve.uniform_interp_iter(SAMPLES, 0.0000001).rev().collect();
+        for (point, point_back) in points.iter().zip(points_back.iter().rev()) {
+            assert_eq!(point, point_back);
+        }
+
+        assert_eq!(0, curve.uniform_interp_iter(0, 0.0000001).len());
+        assert_eq!(None, curve.uniform_interp_iter(0, 0.0000001).next());
+        assert_eq!(None, curve.uniform_interp_iter(0, 0.0000001).next_back());
+    }
+
+    #[test]
+    fn project() {
+        let curve = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 1.0));
+        let nbez: NBez<f64> = NBez::from_slice(curve.as_ref()).unwrap();
+
+        let mut t: f64 = 0.05;
+        while t < 1.0 {
+            let normal = curve.slope(t).unwrap().normalize().perp();
+            let point = curve.interp(t).unwrap() + normal * 0.01;
+
+            for &(proj_t, distance) in &[curve.project(point), nbez.project(point)] {
+                assert!((proj_t - t).abs() <= 0.000001);
+                assert!((distance - 0.01).abs() <= 0.000001);
+            }
+            t += 1.0/30.0;
+        }
+
+        // Points past the ends of the curve project onto the ends.
+        assert_eq!((0.0, 1.0), curve.project(Point2d::new(0.0, -1.0)));
+        assert_eq!(1.0, curve.project(Point2d::new(4.0, 1.0)).0);
+
+        let curve3d: Bez2o<f64, Point3d<f64>> = Bez2o::new(Point3d::new(0.0, 0.0, 0.0), Point3d::new(1.0, 1.0, 1.0), Point3d::new(2.0, 0.0, 2.0));
+        let (proj_t, distance) = curve3d.project(Point3d::new(1.0, 2.0, 1.0));
+        assert!((proj_t - 0.5).abs() <= 0.000001);
+        assert!((distance - 1.5).abs() <= 0.000001);
+
+        // The closest point on a circular arc to a point outside of it is on the line through the
+        // arc's center.
+        let arc = RBez2o::new(Bez2o::new(Point2d::new(1.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0)), [1.0, 0.5f64.sqrt(), 1.0]);
+        let (proj_t, distance) = arc.project(Point2d::new(2.0, 2.0));
+        assert!((proj_t - 0.5).abs() <= 0.000001);
+        assert!((distance - (8.0f64.sqrt() - 1.0)).abs() <= 0.000001);
+
+        // A curve that passes back and forth through the point within a short stretch of `t` still
+        // projects exactly onto it.
+        let spike: NBez<f64, f64> = NBez::from_container(vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 10.0, -10.0, 0.0, 0.0]);
+        let (spike_t, spike_distance) = spike.project(0.5);
+        assert!((spike.interp(spike_t).unwrap() - 0.5).abs() <= 0.000001);
+        assert!(spike_distance <= 0.000001);
+
+        let (left, right) = curve.split(0.3).unwrap();
+        let chain: BezChain<f64, Bez3o<f64>, _> = BezChain::from_container([left[0], left[1], left[2], right[0], right[1], right[2], right[3]]);
+        let (index, chain_t, distance) = chain.project(curve.interp(0.65).unwrap()).unwrap();
+        assert_eq!(1, index);
+        assert!((chain_t - 0.5).abs() <= 0.000001);
+        assert!(distance <= 0.000001);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let curve = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 1.0));
+        let nbez: NBez<f64> = NBez::from_slice(curve.as_ref()).unwrap();
+
+        let (min, max) = curve.bounding_box();
+        assert_eq!((min, max), nbez.bounding_box());
+        assert_eq!((Point2d::new(0.0, -1.0), Point2d::new(3.0, 2.0)), curve.control_box());
+
+        // Every sampled point lies within the box, and the box's edges are touched by the curve.
+        let (mut sampled_min, mut sampled_max) = (curve[0], curve[0]);
+        for point in curve.interp_iter(10000) {
+            assert!(min.x <= point.x && point.x <= max.x);
+            assert!(min.y <= point.y && point.y <= max.y);
+            sampled_min = Point2d::new(sampled_min.x.min(point.x), sampled_min.y.min(point.y));
+            sampled_max = Point2d::new(sampled_max.x.max(point.x), sampled_max.y.max(point.y));
+        }
+        assert!(Vector2d::from(sampled_min - min).len() <= 0.00001);
+        assert!(Vector2d::from(sampled_max - max).len() <= 0.00001);
+
+        // The extremum of a symmetric curve lands exactly in the middle.
+        let arch: Bez2o<f64, Point3d<f64>> = Bez2o::new(Point3d::new(0.0, 0.0, 0.0), Point3d::new(1.0, 2.0, 0.0), Point3d::new(2.0, 0.0, 0.0));
+        assert_eq!((Point3d::new(0.0, 0.0, 0.0), Point3d::new(2.0, 1.0, 0.0)), arch.bounding_box());
+
+        let (left, right) = curve.split(0.3).unwrap();
+        let chain: BezChain<f64, Bez3o<f64>, _> = BezChain::from_container([left[0], left[1], left[2], right[0], right[1], right[2], right[3]]);
+        let (chain_min, chain_max) = chain.bounding_box().unwrap();
+        assert!(Vector2d::from(chain_min - min).len() <= 0.000001);
+        assert!(Vector2d::from(chain_max - max).len() <= 0.000001);
+    }
+
+    #[test]
+    fn split_monotone() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(-1.0, 2.0), Point2d::new(0.5, -0.5));
+        let extrema = curve.extrema();
+        assert_eq!(3, extrema.len());
+        for &t in &extrema {
+            let slope = curve.slope(t).unwrap();
+            assert!(slope.x.abs() <= 0.000001 || slope.y.abs() <= 0.000001);
+        }
+        assert_eq!(2, curve.axis_extrema(0).len());
+        assert_eq!(1, curve.axis_extrema(1).len());
+
+        let pieces = curve.split_monotone();
+        assert_eq!(4, pieces.len());
+        for piece in &pieces {
+            let (mut prev, mut prev_slope) = (piece[0], Vector2d::new(0.0, 0.0));
+            for point in piece.interp_iter(100).skip(1) {
+                let slope = Vector2d::from(point - prev);
+                assert!(slope.x * prev_slope.x >= -0.000001 && slope.y * prev_slope.y >= -0.000001);
+                prev = point;
+                prev_slope = slope;
+            }
+        }
+        assert_eq!(curve[0], pieces[0][0]);
+        assert!(Vector2d::from(curve[3] - pieces[3][3]).len() <= 0.000001);
+
+        let nbez: NBez<f64> = NBez::from_slice(curve.as_ref()).unwrap();
+        assert_eq!(extrema, nbez.extrema());
+        assert_eq!(4, nbez.split_monotone().len());
+    }
+
+    #[test]
+    fn derivative() {
+        let bez3o: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, -1.0));
+        let dbez: Bez2o<f64, Vector2d<f64>> = bez3o.derivative();
+        let ddbez: Bez1o<f64, Vector2d<f64>> = dbez.derivative();
+        let nbez: NBez<f64> = NBez::from_slice(bez3o.as_ref()).unwrap();
+        let dnbez = nbez.derivative();
+        assert_eq!(2, dnbez.order());
+
+        for t in (0..31).map(|t| t as f64 / 30.0) {
+            let slope = bez3o.slope_unbounded(t);
+            assert!((slope - dbez.interp_unbounded(t)).len() <= 0.000000001);
+            assert!((slope - dnbez.interp_unbounded(t)).len() <= 0.000000001);
+            assert!((bez3o.second_derivative_unbounded(t) - ddbez.interp_unbounded(t)).len() <= 0.000000001);
+            assert!((dbez.slope_unbounded(t) - ddbez.interp_unbounded(t)).len() <= 0.000000001);
+        }
+
+        // The derivative of a single point is zero everywhere.
+        let point: Bez<f64, f64, 0> = Bez::from_slice(&[5.0]).unwrap();
+        let dpoint: Bez<f64, f64, 0> = point.derivative();
+        assert_eq!(0.0, dpoint[0]);
+        let npoint: NBez<f64, f64> = NBez::from_slice(&[5.0]).unwrap();
+        assert_eq!(vec![0.0], npoint.derivative().unwrap());
+    }
+
+    #[test]
+    fn inflections() {
+        // Symmetric about its middle, which is where it changes from turning left to turning right.
+        let s_curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 0.0));
+        let inflections = s_curve.inflections();
+        assert_eq!(1, inflections.len());
+        assert!((inflections[0] - 0.5).abs() <= 0.000000001);
+        assert!(s_curve.signed_curvature(0.4).unwrap() * s_curve.signed_curvature(0.6).unwrap() < 0.0);
+        assert_eq!(Some(CubicClass::Serpentine), s_curve.classify());
+        assert!(s_curve.cusps().is_empty());
+
+        let nbez: NBez<f64> = NBez::from_slice(s_curve.as_ref()).unwrap();
+        assert_eq!(Some(CubicClass::Serpentine), nbez.classify());
+        assert_eq!(1, nbez.inflections().len());
+        assert!((nbez.inflections()[0] - 0.5).abs() <= 0.000000001);
+
+        let cusp: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0), Point2d::new(1.0, 0.0));
+        let cusps = cusp.cusps();
+        assert_eq!(1, cusps.len());
+        assert!((cusps[0] - 0.5).abs() <= 0.000000001);
+        assert!(cusp.inflections().is_empty());
+        assert_eq!(Some(CubicClass::Cusp), cusp.classify());
+
+        let looped: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(2.0, 1.0), Point2d::new(-1.0, 1.0), Point2d::new(1.0, 0.0));
+        assert!(looped.inflections().is_empty());
+        assert!(looped.cusps().is_empty());
+        assert_eq!(Some(CubicClass::Loop), looped.classify());
+
+        let arch: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 0.0));
+        assert!(arch.inflections().is_empty());
+        assert_eq!(Some(CubicClass::Quadratic), arch.elevate().classify());
+        assert_eq!(None, arch.classify());
+
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(3.0, 3.0), Point2d::new(2.0, 2.0));
+        assert!(line.inflections().is_empty());
+        assert_eq!(Some(CubicClass::Line), line.classify());
+        let point: Bez3o<f64> = Bez3o::new(Point2d::new(1.0, 1.0), Point2d::new(1.0, 1.0), Point2d::new(1.0, 1.0), Point2d::new(1.0, 1.0));
+        assert!(point.cusps().is_empty());
+        assert_eq!(Some(CubicClass::Point), point.classify());
+    }
+
+    #[test]
+    fn frames() {
+        // A helix-like curve, which turns and twists at the same time.
+        let curve: Bez3o<f64, Point3d<f64>> = Bez3o::new(Point3d::new(1.0, 0.0, 0.0), Point3d::new(1.0, 0.5, 0.3), Point3d::new(0.5, 1.0, 0.6), Point3d::new(0.0, 1.0, 1.0));
+
+        for t in (0..11).map(|t| t as f64 / 10.0) {
+            let frame = curve.frenet_frame(t).unwrap();
+            assert!((frame.tangent.len() - 1.0).abs() <= 0.000000001);
+            assert!((frame.normal.len() - 1.0).abs() <= 0.000000001);
+            assert!(frame.tangent.dot(frame.normal).abs() <= 0.000000001);
+            assert!((frame.tangent.cross(frame.normal) - frame.binormal).len() <= 0.000000001);
+            // The normal points in the direction the curve is turning in.
+            assert!(frame.normal.dot(curve.second_derivative(t).unwrap()) > 0.0);
+            assert_eq!(Some(frame.normal), curve.normal(t));
+        }
+
+        // The torsion is the rate at which the binormal rotates, relative to the distance travelled.
+        let (t, dt) = (0.5, 0.000001);
+        let db = (curve.binormal(t + dt).unwrap() - curve.binormal(t - dt).unwrap()) / (2.0 * dt);
+        let speed = curve.slope(t).unwrap().len();
+        let expected = -db.dot(curve.normal(t).unwrap()) / speed;
+        assert!((curve.torsion(t).unwrap() - expected).abs() <= 0.00001);
+
+        // A flat curve doesn't twist, but its frames are undefined where it's straight.
+        let flat: Bez2o<f64, Point3d<f64>> = Bez2o::new(Point3d::new(0.0, 0.0, 0.0), Point3d::new(1.0, 1.0, 0.0), Point3d::new(2.0, 0.0, 0.0));
+        assert!(flat.torsion(0.3).unwrap().abs() <= 0.000000001);
+        let straight: Bez1o<f64, Point3d<f64>> = Bez1o::new(Point3d::new(0.0, 0.0, 0.0), Point3d::new(1.0, 1.0, 1.0));
+        assert!(straight.tangent(0.5).is_some());
+        assert_eq!(None, straight.normal(0.5));
+        assert_eq!(None, straight.torsion(0.5));
+
+        // Rotation-minimizing frames stay perpendicular, and don't flip over across an inflection.
+        let s_curve: Bez3o<f64, Point3d<f64>> = Bez3o::new(Point3d::new(0.0, 0.0, 0.0), Point3d::new(1.0, 1.0, 0.0), Point3d::new(2.0, -1.0, 0.0), Point3d::new(3.0, 0.0, 0.0));
+        let frames: Vec<Frame<f64>> = s_curve.rotation_minimizing_frames(20).collect();
+        assert_eq!(21, frames.len());
+        for (i, frame) in frames.iter().enumerate() {
+            assert_eq!(s_curve.interp(i as f64 / 20.0).unwrap(), frame.point);
+            assert!(frame.tangent.dot(frame.normal).abs() <= 0.000000001);
+            assert!((frame.binormal.len() - 1.0).abs() <= 0.000000001);
+            // The curve lies in the xy plane, so the binormal should stay pointing along z.
+            assert!((frame.binormal.z.abs() - 1.0).abs() <= 0.000000001);
+            assert_eq!(frames[0].binormal.z.signum(), frame.binormal.z.signum());
+        }
+        assert!(s_curve.frenet_frame(0.25).unwrap().binormal.z * s_curve.frenet_frame(0.75).unwrap().binormal.z < 0.0);
+
+        let frames: Vec<Frame<f64>> = curve.rotation_minimizing_frames(50).collect();
+        for pair in frames.windows(2) {
+            assert!(pair[1].tangent.dot(pair[1].normal).abs() <= 0.000000001);
+            assert!(pair[0].normal.dot(pair[1].normal) > 0.99);
+        }
+    }
+
+    #[test]
+    fn intersect_line() {
+        // A hump between (0, 0) and (2, 0), which reaches a height of 1 at its middle.
+        let hump: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, 0.0));
+
+        let line = Line::infinite(Point2d::new(-1.0, 0.5), Point2d::new(0.0, 0.5));
+        let intersections = hump.intersect_line(&line);
+        assert_eq!(2, intersections.len());
+        for intersection in &intersections {
+            assert!((intersection.point.y - 0.5).abs() <= 0.000000001);
+            assert_eq!(hump.interp(intersection.t), Some(intersection.point));
+            assert!((intersection.line_t - (intersection.point.x + 1.0)).abs() <= 0.000000001);
+        }
+        assert!(intersections[0].t < intersections[1].t);
+        assert!(intersections[0].point.x < 1.0 && intersections[1].point.x > 1.0);
+
+        // A ray pointing right from the middle only hits the right half of the hump.
+        let ray = Line::ray(Point2d::new(1.0, 0.5), Point2d::new(2.0, 0.5));
+        let ray_hits = hump.intersect_line(&ray);
+        assert_eq!(1, ray_hits.len());
+        assert!((ray_hits[0].t - intersections[1].t).abs() <= 0.000000001);
+
+        let segment = Line::segment(Point2d::new(0.0, 0.5), Point2d::new(1.0, 0.5));
+        assert_eq!(1, hump.intersect_line(&segment).len());
+        let short = Line::segment(Point2d::new(0.6, 0.5), Point2d::new(1.4, 0.5));
+        assert!(hump.intersect_line(&short).is_empty());
+
+        // A vertical line through the hump's peak, which the NBez version should agree with.
+        let vertical = Line::infinite(Point2d::new(1.0, -5.0), Point2d::new(1.0, 5.0));
+        let nbez: NBez<f64> = NBez::from_slice(hump.as_ref()).unwrap();
+        let hits = nbez.intersect_line(&vertical);
+        assert_eq!(1, hits.len());
+        assert!((hits[0].t - 0.5).abs() <= 0.000000001);
+        assert!((hits[0].line_t - 0.6).abs() <= 0.000000001);
+
+        // The line through the ends hits the chain at the join exactly once.
+        let (left, right) = hump.split(0.5).unwrap();
+        let chain: BezChain<f64, Bez2o<f64>, _> = BezChain::from_container([left[0], left[1], right[0], right[1], right[2]]);
+        let horizontal = Line::infinite(Point2d::new(0.0, 0.5), Point2d::new(1.0, 0.5));
+        let chain_hits = chain.intersect_line(&horizontal);
+        assert_eq!(2, chain_hits.len());
+        assert_eq!((0, 1), (chain_hits[0].0, chain_hits[1].0));
+        let peak = Line::infinite(Point2d::new(0.0, 1.0), Point2d::new(1.0, 1.0));
+        let peak_hits = chain.intersect_line(&peak);
+        assert_eq!(1, peak_hits.len());
+        assert_eq!(0, peak_hits[0].0);
+
+        assert!(hump.intersect_line(&Line::infinite(Point2d::new(0.0, 0.5), Point2d::new(0.0, 0.5))).is_empty());
+
+        // A line tangent to the hump touches it once, at the point of tangency.
+        let tangent = Line::infinite(Point2d::new(0.6, 0.84), Point2d::new(2.6, 2.44));
+        let tangent_hits = hump.intersect_line(&tangent);
+        assert_eq!(1, tangent_hits.len());
+        assert!((tangent_hits[0].t - 0.3).abs() <= 0.000001);
+    }
+
+    #[test]
+    fn intersections() {
+        const TOLERANCE: f64 = 0.000001;
+        let hump: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, 0.0));
+
+        // Crossing a line should find the same points as `intersect_line`.
+        let line: Bez1o<f64> = Bez1o::new(Point2d::new(0.0, 0.5), Point2d::new(2.0, 0.5));
+        let pairs = hump.intersections(&line, TOLERANCE);
+        let expected = hump.intersect_line(&Line::segment(line[0], line[1]));
+        assert_eq!(2, pairs.len());
+        for (pair, intersection) in pairs.iter().zip(expected.iter()) {
+            assert!((pair.0 - intersection.t).abs() <= TOLERANCE);
+            assert!((pair.1 - intersection.line_t).abs() <= TOLERANCE);
+        }
+
+        // An S-shaped cubic crossing the hump, as an `NBez`.
+        let s_curve: NBez<f64> = NBez::from_slice(&[Point2d::new(0.0, 1.0), Point2d::new(1.0, -1.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, 0.5)]).unwrap();
+        let pairs = hump.intersections(&s_curve, TOLERANCE);
+        assert!(!pairs.is_empty());
+        for pair in &pairs {
+            let distance = Vector2d::from(hump.interp(pair.0).unwrap() - s_curve.interp(pair.1).unwrap()).len();
+            assert!(distance <= TOLERANCE);
+        }
+        for window in pairs.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+
+        // Touching the top of the hump gives a single pair.
+        let top: Bez1o<f64> = Bez1o::new(Point2d::new(0.0, 1.0), Point2d::new(2.0, 1.0));
+        let pairs = hump.intersections(&top, TOLERANCE);
+        assert_eq!(1, pairs.len());
+        assert!((pairs[0].0 - 0.5).abs() <= 0.001);
+        assert!((pairs[0].1 - 0.5).abs() <= 0.001);
+
+        // Overlapping with its own right half gives the ends of the overlap.
+        let (_, right) = hump.split(0.5).unwrap();
+        let pairs = hump.intersections(&right, TOLERANCE);
+        assert_eq!(2, pairs.len());
+        assert!((pairs[0].0 - 0.5).abs() <= 0.001 && pairs[0].1.abs() <= 0.001);
+        assert!((pairs[1].0 - 1.0).abs() <= 0.001 && (pairs[1].1 - 1.0).abs() <= 0.001);
+
+        // A tolerance of zero gets raised instead of subdividing the overlap forever.
+        assert_eq!(2, hump.intersections(&right, 0.0).len());
+
+        // A cubic overlapping with the part of itself before `t = 0.6` gives the ends of that part,
+        // which start at the ends of both curves.
+        let cubic: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 1.0));
+        let (left, _) = cubic.split(0.6).unwrap();
+        let pairs = cubic.intersections(&left, TOLERANCE);
+        assert_eq!(2, pairs.len());
+        assert!(pairs[0].0.abs() <= 0.001 && pairs[0].1.abs() <= 0.001);
+        assert!((pairs[1].0 - 0.6).abs() <= 0.001 && (pairs[1].1 - 1.0).abs() <= 0.001);
+
+        // Curves that meet at both of their ends without overlapping in between cross twice.
+        let dip: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, -2.0), Point2d::new(2.0, 0.0));
+        let pairs = hump.intersections(&dip, TOLERANCE);
+        assert_eq!(2, pairs.len());
+        assert!(pairs[0].0.abs() <= 0.001 && pairs[0].1.abs() <= 0.001);
+        assert!((pairs[1].0 - 1.0).abs() <= 0.001 && (pairs[1].1 - 1.0).abs() <= 0.001);
+
+        let far: Bez1o<f64> = Bez1o::new(Point2d::new(0.0, 5.0), Point2d::new(2.0, 5.0));
+        assert!(hump.intersections(&far, TOLERANCE).is_empty());
+    }
+
+    #[test]
+    fn self_intersections() {
+        const TOLERANCE: f64 = 0.000001;
+
+        // Crosses itself at (0.5, 0.5625), where `x(t) = 0.5` and `t` is symmetric about `0.5`.
+        let looped: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(2.0, 1.0), Point2d::new(-1.0, 1.0), Point2d::new(1.0, 0.0));
+        let crossings = looped.self_intersections(TOLERANCE);
+        assert_eq!(1, crossings.len());
+        let (t0, t1) = crossings[0];
+        assert!(t0 < t1);
+        assert!((t0 + t1 - 1.0).abs() <= 0.0001);
+        assert!(Vector2d::from(looped.interp(t0).unwrap() - looped.interp(t1).unwrap()).len() <= TOLERANCE);
+
+        let nbez: NBez<f64> = NBez::from_slice(looped.as_ref()).unwrap();
+        assert_eq!(1, nbez.self_intersections(TOLERANCE).len());
+
+        let s_curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 0.0));
+        assert!(s_curve.self_intersections(TOLERANCE).is_empty());
+        let cusp: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0), Point2d::new(1.0, 0.0));
+        assert!(cusp.self_intersections(TOLERANCE).is_empty());
+
+        // A closed triangle doesn't cross itself, but a bow tie does, between its first and third sides.
+        let triangle: BezChain<f64, Bez1o<f64>, _> = BezChain::from_container([Point2d::new(0.0, 0.0), Point2d::new(2.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 0.0)]);
+        assert!(triangle.self_intersections(TOLERANCE).is_empty());
+        let bow_tie: BezChain<f64, Bez1o<f64>, _> = BezChain::from_container([Point2d::new(0.0, 0.0), Point2d::new(2.0, 2.0), Point2d::new(2.0, 0.0), Point2d::new(0.0, 2.0), Point2d::new(0.0, 0.0)]);
+        let crossings = bow_tie.self_intersections(TOLERANCE);
+        assert_eq!(1, crossings.len());
+        let ((index0, t0), (index1, t1)) = crossings[0];
+        assert_eq!((0, 2), (index0, index1));
+        assert!((t0 - 0.5).abs() <= TOLERANCE && (t1 - 0.5).abs() <= TOLERANCE);
+
+        // The loop gets found when it's one of the curves in a chain, too.
+        let chain: BezChain<f64, Bez3o<f64>, _> = BezChain::from_container([looped[0], looped[1], looped[2], looped[3], Point2d::new(2.0, 0.0), Point2d::new(3.0, 0.0), Point2d::new(4.0, 0.0)]);
+        let crossings = chain.self_intersections(TOLERANCE);
+        assert_eq!(1, crossings.len());
+        assert_eq!((0, 0), ((crossings[0].0).0, (crossings[0].1).0));
+    }
+
+    #[test]
+    fn roots() {
+        assert_eq!(vec![0.5], Bez1o::new(-1.0, 1.0).roots());
+
+        // `(2t - 1)^2`, which touches zero without crossing it
+        let double: Bez2o<f64, f64> = Bez2o::new(1.0, -1.0, 1.0);
+        let roots = double.roots();
+        assert_eq!(1, roots.len());
+        assert!((roots[0] - 0.5).abs() <= 0.000001);
+
+        // `(t - 0.7)^2`, whose double root isn't at a point the subdivisions land on exactly
+        let roots = Bez2o::new(0.49f64, -0.21, 0.09).roots();
+        assert_eq!(1, roots.len());
+        assert!((roots[0] - 0.7).abs() <= 0.000001);
+
+        // `(t - 0.3)^2 (t - 0.8)`, with a double root next to a simple one
+        let mixed: Bez3o<f64, f64> = Bez3o::new(-0.072, 0.118, -0.476/3.0, 0.098);
+        let roots = mixed.roots();
+        assert_eq!(2, roots.len());
+        assert!((roots[0] - 0.3).abs() <= 0.000001);
+        assert!((roots[1] - 0.8).abs() <= 0.000000001);
+
+        // `(t - 0.25)(t - 0.5)(t - 0.75)`
+        let cubic: Bez3o<f64, f64> = Bez3o::new(-0.09375, 13.0/96.0, -13.0/96.0, 0.09375);
+        let roots = cubic.roots();
+        assert_eq!(3, roots.len());
+        for (root, expected) in roots.iter().zip([0.25, 0.5, 0.75].iter()) {
+            assert!((root - expected).abs() <= 0.000000001);
+        }
+
+        // Roots at the ends are included, and there are no roots where the curve stays positive.
+        assert_eq!(vec![0.0, 1.0], Bez2o::new(0.0, 1.0, 0.0).roots());
+        assert!(Bez3o::new(1.0, -0.1, 0.5, 2.0).roots().is_empty());
+        assert!(Bez2o::new(0.0, 0.0, 0.0).roots().is_empty());
+
+        // A wiggly high-order curve, checked against its own interpolation.
+        let points: Vec<f64> = (0..20).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 } * (1.0 + i as f64 * 0.1)).collect();
+        let nbez: NBez<f64, f64> = NBez::from_slice(&points).unwrap();
+        let roots = nbez.roots();
+        assert!(!roots.is_empty());
+        for root in &roots {
+            assert!(nbez.interp(*root).unwrap().abs() <= 0.000000001);
+        }
+        for (a, b) in roots.iter().zip(roots.iter().skip(1)) {
+            assert!(a < b);
+        }
+    }
+
+    #[test]
+    fn transform() {
+        use std::f64::consts::PI;
+
+        let bez3o: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, -1.0), Point2d::new(4.0, 1.0));
+        let transform = Transform2d::rotate(PI / 2.0).then(&Transform2d::translate(1.0, 2.0));
+        let rotated = bez3o.transform(&transform);
+        for t in (0..11).map(|t| t as f64 / 10.0) {
+            let point = bez3o.interp(t).unwrap();
+            let expected = Point2d::new(1.0 - point.y, 2.0 + point.x);
+            assert!(Vector2d::from(rotated.interp(t).unwrap() - expected).len() <= 0.000000001);
+        }
+        assert_eq!(bez3o.as_ref(), bez3o.transform(&Transform2d::identity()).as_ref());
+
+        let bez3d: Bez2o<f64, Point3d<f64>> = Bez2o::new(Point3d::new(1.0, 0.0, 0.0), Point3d::new(0.0, 1.0, 0.0), Point3d::new(0.0, 0.0, 1.0));
+        let spun = bez3d.transform(&Transform3d::rotate(Vector3d::new(1.0, 1.0, 1.0), 2.0 * PI / 3.0));
+        assert!(Vector3d::from(spun[0] - bez3d[1]).len() <= 0.000000001);
+        assert!(Vector3d::from(spun[2] - bez3d[0]).len() <= 0.000000001);
+        let scaled = bez3d.transform(&Transform3d::scale(2.0, 2.0, 2.0).then(&Transform3d::translate(0.0, 0.0, 1.0)));
+        assert_eq!(Point3d::new(0.0, 0.0, 3.0), scaled[2]);
+
+        let chain: BezChain<f64, Bez1o<f64>, _> = BezChain::from_container([Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0), Point2d::new(1.0, 1.0)]);
+        let moved = chain.transform(&Transform2d::scale(2.0, 3.0));
+        assert_eq!(vec![Point2d::new(0.0, 0.0), Point2d::new(2.0, 0.0), Point2d::new(2.0, 3.0)], moved.unwrap());
+
+        // A perspective divide by `x + 1`, which bends the curve's parameterization.
+        let perspective = Projective2d::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 0.0, 1.0]]);
+        let projected = bez3o.transform_projective(&perspective);
+        for t in (0..11).map(|t| t as f64 / 10.0) {
+            let point = bez3o.interp(t).unwrap();
+            let expected = Point2d::new(point.x / (point.x + 1.0), point.y / (point.x + 1.0));
+            assert!(Vector2d::from(projected.interp(t).unwrap() - expected).len() <= 0.000000001);
+        }
+        let affine = bez3o.transform_projective(&Projective2d::from(Transform2d::translate(1.0, 0.0)));
+        assert_eq!(&[1.0; 4][..], &affine.weights()[..]);
+
+        // A perspective divide by `1 - x` sends the control point at `x = 1` to infinity, which the
+        // rational curve keeps as a direction.
+        let perspective = Projective2d::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 1.0]]);
+        let projected = bez3o.transform_projective(&perspective);
+        assert_eq!(0.0, projected.weights()[1]);
+        let (left, right) = projected.split(0.5).unwrap();
+        let elevated = projected.elevate();
+        let moved = projected.transform(&Transform2d::translate(1.0, 2.0));
+        let derivative = projected.derivative();
+        for t in (0..11).map(|t| t as f64 / 10.0) {
+            let point = bez3o.interp(t).unwrap();
+            let expected = Point2d::new(point.x / (1.0 - point.x), point.y / (1.0 - point.x));
+            let tolerance = 0.000000001 * (1.0 + Vector2d::from(expected).len());
+            assert!(Vector2d::from(projected.interp(t).unwrap() - expected).len() <= tolerance);
+            assert!(Vector2d::from(elevated.interp(t).unwrap() - expected).len() <= tolerance);
+            assert!(Vector2d::from(moved.interp(t).unwrap() - expected - Point2d::new(1.0, 2.0)).len() <= tolerance);
+            assert!(Vector2d::from(left.interp(t).unwrap() - projected.interp(t * 0.5).unwrap()).len() <= tolerance);
+            assert!(Vector2d::from(right.interp(t).unwrap() - projected.interp(0.5 + t * 0.5).unwrap()).len() <= tolerance);
+
+            let slope = projected.slope(t).unwrap();
+            assert!((slope - derivative.interp(t).unwrap()).len() <= 0.000001 * (1.0 + slope.len()));
+        }
+    }
+
+    #[test]
+    fn subcurve() {
+        let bez3o: Bez3o<f64, f64> = Bez3o::new(0.0, 2.0, -1.0, 3.0);
+        let nbez: NBez<f64, f64> = NBez::from_slice(bez3o.as_ref()).unwrap();
+
+        let sub = bez3o.subcurve(0.25, 0.75).unwrap();
+        let nsub = nbez.subcurve(0.25, 0.75).unwrap();
+        let reversed = bez3o.subcurve(0.75, 0.25).unwrap();
+        for t in (0..31).map(|t| t as f64 / 30.0) {
+            let expected = bez3o.interp(0.25 + t * 0.5).unwrap();
+            assert!((sub.interp(t).unwrap() - expected).abs() <= 0.000000001);
+            assert!((nsub.interp(t).unwrap() - expected).abs() <= 0.000000001);
+            assert!((reversed.interp(1.0 - t).unwrap() - expected).abs() <= 0.000000001);
+            assert!((bez3o.reverse().interp(t).unwrap() - bez3o.interp(1.0 - t).unwrap()).abs() <= 0.000000001);
+        }
+
+        // Subcurves reaching the ends, and ones of a single point.
+        assert_eq!(bez3o.as_ref(), bez3o.subcurve(0.0, 1.0).unwrap().as_ref());
+        assert_eq!([3.0, -1.0, 2.0, 0.0], bez3o.subcurve(1.0, 0.0).unwrap().as_ref());
+        assert_eq!([0.0; 4], bez3o.subcurve(0.0, 0.0).unwrap().as_ref());
+        assert_eq!([3.0; 4], bez3o.subcurve(1.0, 1.0).unwrap().as_ref());
+        assert!(bez3o.subcurve(-0.5, 0.5).is_none());
+
+        let reversed: Vec<f64> = nbez.reverse().unwrap();
+        assert_eq!(vec![3.0, -1.0, 2.0, 0.0], reversed);
+    }
+
+    #[test]
+    fn elevate_to() {
+        let bez2o: Bez2o<f64, f64> = Bez2o::new(0.0, 2.0, -1.0);
+
+        // Elevating in one step matches elevating one order at a time.
+        let stepped: Bez5o<f64, f64> = bez2o.elevate().elevate().elevate();
+        let direct: NBez<f64, f64> = bez2o.elevate_to(5).unwrap();
+        assert_eq!(5, direct.order());
+        let direct_points: &[f64] = direct.as_ref();
+        for (a, b) in direct_points.iter().zip(stepped.as_ref().iter()) {
+            assert!((a - b).abs() <= 0.000000001);
+        }
+        test_poly_eq(&bez2o, &direct);
+        test_poly_eq(&bez2o, &bez2o.elevate_to(40).unwrap());
+
+        assert_eq!(vec![0.0, 2.0, -1.0], bez2o.elevate_to(2).unwrap().unwrap());
+        assert!(bez2o.elevate_to(1).is_none());
+
+        let line: Bez1o<f64, f64> = Bez1o::new(1.0, -1.0);
+        let [a, b] = bez2o.elevate_common(&line).unwrap();
+        assert_eq!((2, 2), (a.order(), b.order()));
+        test_poly_eq(&line, &b);
+        assert_eq!(vec![0.0, 2.0, -1.0], a.unwrap());
+
+        let arc = RBez2o::new(Bez2o::new(Point2d::new(1.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0)),
+                              [1.0, 0.5f64.sqrt(), 1.0]);
+        let chord: Bez1o<f64, Point2d<f64>> = Bez1o::new(Point2d::new(1.0, 0.0), Point2d::new(0.0, 1.0));
+        assert!(arc.elevate_common(&chord).is_none());
+        assert!(chord.elevate_common(&arc).is_none());
+    }
+
+    #[test]
+    fn rational() {
+        use std::f64::consts::FRAC_1_SQRT_2;
+
+        // A quarter of the unit circle, which a polynomial curve can only approximate.
+        let arc: RBez2o<f64> = RBez2o::new(Bez2o::new(Point2d::new(1.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0)), [1.0, FRAC_1_SQRT_2, 1.0]);
+        let narc = RNBez::from_weighted(arc.points().to_vec(), arc.weights().to_vec());
+        let (left, right) = arc.split(0.3).unwrap();
+        let elevated = arc.elevate();
+        let derivative = arc.derivative();
+        for t in (0..21).map(|t| t as f64 / 20.0) {
+            let point = arc.interp(t).unwrap();
+            assert!((Vector2d::from(point).len() - 1.0).abs() <= 0.000000001);
+            assert!(Vector2d::from(narc.interp(t).unwrap() - point).len() <= 0.000000001);
+            assert!(Vector2d::from(elevated.interp(t).unwrap() - point).len() <= 0.000000001);
+            assert!(Vector2d::from(arc.reverse().interp(1.0 - t).unwrap() - point).len() <= 0.000000001);
+
+            // The slope is tangent to the circle, and matches the derivative curve.
+            let slope = arc.slope(t).unwrap();
+            assert!(slope.dot(Vector2d::from(point)).abs() <= 0.000000001);
+            assert!((slope - derivative.interp(t).unwrap()).len() <= 0.000000001);
+            assert!((slope - narc.slope(t).unwrap()).len() <= 0.000000001);
+
+            // Curvature is the reciprocal of the radius everywhere along a circle.
+            assert!((arc.curvature(t).unwrap() - 1.0).abs() <= 0.000001);
+
+            assert!(Vector2d::from(left.interp(t).unwrap() - arc.interp(t * 0.3).unwrap()).len() <= 0.000000001);
+            assert!(Vector2d::from(right.interp(t).unwrap() - arc.interp(0.3 + t * 0.7).unwrap()).len() <= 0.000000001);
+        }
+
+        // The curve bulges out past its end points to the circle, and not to its middle control point.
+        let (min, max) = arc.bounding_box();
+        assert_eq!((Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0)), (min, max));
+        let diagonal = Line::infinite(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0));
+        let hits = arc.intersect_line(&diagonal);
+        assert_eq!(1, hits.len());
+        assert!((hits[0].t - 0.5).abs() <= 0.000000001);
+        assert!((hits[0].line_t - FRAC_1_SQRT_2).abs() <= 0.000000001);
+
+        // A zero weight makes the middle point a point at infinity, in the direction of the top of the
+        // unit circle, which bends the curve into the upper half of the circle.
+        let semicircle: RBez2o<f64> = RBez2o::new(Bez2o::new(Point2d::new(1.0, 0.0), Point2d::new(0.0, 1.0), Point2d::new(-1.0, 0.0)), [1.0, 0.0, 1.0]);
+        for t in (0..21).map(|t| t as f64 / 20.0) {
+            assert!((Vector2d::from(semicircle.interp(t).unwrap()).len() - 1.0).abs() <= 0.000000001);
+        }
+        let (min, max) = semicircle.bounding_box();
+        assert!(Vector2d::from(min - Point2d::new(-1.0, 0.0)).len() <= 0.000000001);
+        assert!(Vector2d::from(max - Point2d::new(1.0, 1.0)).len() <= 0.000000001);
+        let hits = semicircle.intersect_line(&Line::infinite(Point2d::new(0.0, -1.0), Point2d::new(0.0, 1.0)));
+        assert_eq!(1, hits.len());
+        assert!((hits[0].t - 0.5).abs() <= 0.000000001);
+        let (t, distance) = semicircle.project(Point2d::new(0.0, 2.0));
+        assert!((t - 0.5).abs() <= 0.000001);
+        assert!((distance - 1.0).abs() <= 0.000000001);
+
+        // Rational curves can't be elevated to polynomials unless their weights are all the same.
+        assert!(arc.elevate_to(3).is_none());
+        assert_eq!(&[1.0; 3][..], &RBez2o::from(arc.unweighted()).weights()[..]);
+        let uniform = RBez2o::<f64>::from_weighted_slice(&arc.points(), &[2.0; 3]).unwrap();
+        assert_eq!(arc.points().into_owned(), uniform.elevate_to(2).unwrap().unwrap());
+    }
+
+    #[test]
+    fn conic() {
+        use std::f64::consts::PI;
+
+        let center = Point2d::new(1.0, -2.0);
+        let arc = RBez2o::circle_arc(center, 3.0, 0.5, 2.5).unwrap();
+        let (cubic, error) = Bez3o::circle_arc(center, 3.0, 0.5, PI / 2.0).unwrap();
+        assert!(error <= 3.0 * 0.00028);
+        for t in (0..21).map(|t| t as f64 / 20.0) {
+            assert!((Vector2d::from(arc.interp(t).unwrap() - center).len() - 3.0).abs() <= 0.000000001);
+            assert!((Vector2d::from(cubic.interp(t).unwrap() - center).len() - 3.0).abs() <= error);
+        }
+        assert!(Vector2d::from(arc.interp(1.0).unwrap() - Point2d::new(1.0 + 3.0 * 3.0f64.cos(), -2.0 + 3.0 * 3.0f64.sin())).len() <= 0.000000001);
+        assert!(RBez2o::circle_arc(center, 1.0, 0.0, PI).is_none());
+
+        // Points on the ellipse satisfy its equation once the rotation is undone.
+        let ellipse = RBez2o::ellipse_arc(center, Vector2d::new(4.0, 1.0), PI / 6.0, -1.0, 2.0).unwrap();
+        let on_ellipse = |p: Point2d<f64>| {
+            let (sin, cos) = (PI / 6.0).sin_cos();
+            let (x, y) = (p.x - center.x, p.y - center.y);
+            let (u, v) = (cos * x + sin * y, cos * y - sin * x);
+            u * u / 16.0 + v * v
+        };
+        for t in (0..21).map(|t| t as f64 / 20.0) {
+            assert!((on_ellipse(ellipse.interp(t).unwrap()) - 1.0).abs() <= 0.000000001);
+        }
+
+        // Points on a parabola are as far from the focus as from the directrix, and points on a
+        // hyperbola are `e` times as far.
+        let parabola = RBez2o::parabola(Point2d::new(0.0, 0.0), Point2d::new(0.0, 1.0), -3.0, 2.0).unwrap();
+        let hyperbola = RBez2o::hyperbola(Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0), 2.0, -1.0, 1.5).unwrap();
+        for t in (0..21).map(|t| t as f64 / 20.0) {
+            let p = parabola.interp(t).unwrap();
+            assert!((Vector2d::from(p - Point2d::new(0.0, 1.0)).len() - (p.y + 1.0)).abs() <= 0.000000001);
+
+            // With `a = 1` and `c = 2`, the center is at `(-1, 0)` and the directrix at `x = -0.5`.
+            let h = hyperbola.interp(t).unwrap();
+            assert!((Vector2d::from(h - Point2d::new(1.0, 0.0)).len() - 2.0 * (h.x + 0.5)).abs() <= 0.000000001);
+        }
+        assert_eq!(Point2d::new(3.0, 2.25), parabola.points()[0]);
+        assert!(RBez2o::hyperbola(Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0), 1.0, 0.0, 1.0).is_none());
+    }
+
+    #[test]
+    fn svg_arc() {
+        use std::f64::consts::PI;
+
+        // A half circle from `(0, 0)` to `(2, 0)`, going through `(1, -1)` with the sweep flag set.
+        let from = Point2d::new(0.0, 0.0);
+        let to = Point2d::new(2.0, 0.0);
+        let half = BezChain::from_svg_arc(from, Vector2d::new(1.0, 1.0), 0.0, false, true, to);
+        assert_eq!(2, half.iter().len());
+        for curve in half.iter() {
+            for t in (0..11).map(|t| t as f64 / 10.0) {
+                let p = curve.interp(t).unwrap();
+                assert!((Vector2d::from(p - Point2d::new(1.0, 0.0)).len() - 1.0).abs() <= 0.0003);
+                assert!(p.y <= 0.0000001);
+            }
+        }
+        assert_eq!(Some(to), half.iter().next_back().map(|c| c[3]));
+
+        // Radii that are too small get scaled up, so this is the same half circle going the other way.
+        let other = BezChain::from_svg_arc(from, Vector2d::new(0.5, 0.5), 0.0, false, false, to);
+        assert!((other.get(0).unwrap().interp(1.0).unwrap().y - 1.0).abs() <= 0.000000001);
+
+        // The large arc of a rotated ellipse takes three quarter turns or more.
+        let large = BezChain::from_svg_arc(from, Vector2d::new(2.0, 1.0), PI / 4.0, true, false, Point2d::new(1.0, 1.0));
+        assert!(large.iter().len() >= 3);
+        assert_eq!(Point2d::new(1.0, 1.0), large.iter().next_back().unwrap()[3]);
+
+        assert_eq!(0, BezChain::from_svg_arc(from, Vector2d::new(1.0, 1.0), 0.0, false, false, from).iter().len());
+        let line = BezChain::from_svg_arc(from, Vector2d::new(0.0, 1.0), 0.0, false, false, to);
+        assert_eq!(Point2d::new(1.0, 0.0), line.get(0).unwrap().interp(0.5).unwrap());
+    }
+
+    #[test]
+    fn spline() {
+        let points = vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, -1.0), Point2d::new(4.0, 1.0), Point2d::new(6.0, 3.0), Point2d::new(7.0, 0.0)];
+        let spline: BSpline<f64> = BSpline::uniform_clamped(3, points.clone()).unwrap();
+        assert_eq!(&[0.0, 0.0, 0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0, 1.0, 1.0], spline.knots());
+        assert_eq!(Some(points[0]), spline.interp(0.0));
+        assert_eq!(Some(points[5]), spline.interp(1.0));
+        assert!(spline.interp(1.5).is_none());
+
+        // Each segment traces the piece of the spline between two adjacent knots.
+        let segments: Vec<Bez3o<f64>> = spline.segments().unwrap();
+        let nsegments: Vec<NBez<f64>> = spline.segments().unwrap();
+        assert_eq!(3, segments.len());
+        assert!(spline.segments::<Bez2o<f64>>().is_none());
+        let inserted = spline.insert_knot(0.5).unwrap();
+        assert_eq!(11, inserted.knots().len());
+        let derivative = spline.derivative();
+        for (i, (segment, nsegment)) in segments.iter().zip(nsegments.iter()).enumerate() {
+            for t in (0..11).map(|t| t as f64 / 10.0) {
+                let u = (i as f64 + t) / 3.0;
+                let point = spline.interp(u).unwrap();
+                assert!(Vector2d::from(segment.interp(t).unwrap() - point).len() <= 0.000000001);
+                assert!(Vector2d::from(nsegment.interp(t).unwrap() - point).len() <= 0.000000001);
+                assert!(Vector2d::from(inserted.interp(u).unwrap() - point).len() <= 0.000000001);
+
+                // The segments run three times as fast, as each one covers a third of the domain.
+                let slope = spline.slope(u).unwrap();
+                assert!((segment.slope(t).unwrap() - slope * (1.0 / 3.0)).len() <= 0.000000001);
+                assert!((derivative.interp(u).unwrap() - slope).len() <= 0.000000001);
+                assert!((inserted.slope(u).unwrap() - slope).len() <= 0.000000001);
+            }
+        }
+        for u in [-0.5, 1.5] {
+            assert!((derivative.interp_unbounded(u) - spline.slope_unbounded(u)).len() <= 0.000000001);
+        }
+
+        // A spline with a single piece and clamped knots is a bezier curve.
+        let bez3o: Bez3o<f64> = Bez3o::new(points[0], points[1], points[2], points[3]);
+        let single = BSpline::new(3, vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0], points[..4].to_vec()).unwrap();
+        assert_eq!(vec![bez3o.as_ref().to_vec()], single.segments::<NBez<f64>>().unwrap().into_iter().map(|b| b.unwrap()).collect::<Vec<_>>());
+        assert!(BSpline::new(3, vec![0.0; 8], points[..4].to_vec()).is_none());
+        assert!(BSpline::new(3, vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0], points[..4].to_vec()).is_none());
+
+        // The quarter circle as a NURBS curve.
+        use std::f64::consts::FRAC_1_SQRT_2;
+        let arc_points = [Point2d::new(1.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0)];
+        let nurbs = Nurbs::new(2, vec![0.0, 0.0, 0.0, 2.0, 2.0, 2.0], &arc_points, &[1.0, FRAC_1_SQRT_2, 1.0]).unwrap();
+        let split = nurbs.insert_knot(0.5).unwrap().insert_knot(0.5).unwrap();
+        let pieces: Vec<RBez2o<f64>> = split.segments().unwrap();
+        assert_eq!(2, pieces.len());
+        assert!(nurbs.segments::<RBez3o<f64>>().is_none());
+        assert_eq!(vec![1.0, FRAC_1_SQRT_2, 1.0], nurbs.weights());
+        for u in (0..21).map(|u| u as f64 / 10.0) {
+            let point = nurbs.interp(u).unwrap();
+            assert!((Vector2d::from(point).len() - 1.0).abs() <= 0.000000001);
+            assert!(Vector2d::from(split.interp(u).unwrap() - point).len() <= 0.000000001);
+            assert!(nurbs.slope(u).unwrap().dot(Vector2d::from(point)).abs() <= 0.000000001);
+        }
+        assert!(Vector2d::from(pieces[1].interp(1.0).unwrap() - arc_points[2]).len() <= 0.000000001);
+        assert!(Vector2d::from(pieces[0].interp(1.0).unwrap() - nurbs.interp(0.5).unwrap()).len() <= 0.000000001);
+    }
+
+    #[test]
+    fn reduce() {
+        // Reducing an elevated curve gets back the original.
+        let bez3o: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, -1.0), Point2d::new(4.0, 1.0));
+        let (reduced, error): (Bez3o<f64>, f64) = bez3o.elevate().reduce();
+        assert!(error <= 0.000000001);
+        for (a, b) in reduced.as_ref().iter().zip(bez3o.as_ref().iter()) {
+            assert!(Vector2d::from(*a - *b).len() <= 0.000000001);
+        }
+
+        // A genuine cubic can't be matched by a quadratic, but the error bound should hold, and the
+        // end points should stay put.
+        let (quad, error): (Bez2o<f64>, f64) = bez3o.reduce();
+        let (nquad, nerror) = NBez::<f64>::from_slice(bez3o.as_ref()).unwrap().reduce();
+        assert_eq!(error, nerror);
+        assert_eq!(quad.as_ref(), &nquad.unwrap()[..]);
+        assert!(error > 0.0);
+        assert_eq!(bez3o.start, quad.start);
+        assert_eq!(bez3o.end, quad.end);
+        for t in (0..101).map(|t| t as f64 / 100.0) {
+            assert!(Vector2d::from(bez3o.interp(t).unwrap() - quad.interp(t).unwrap()).len() <= error);
+        }
+
+        // The quadratic is the best fit, so nudging its control point can only make the fit worse.
+        let squared_error = |curve: &Bez2o<f64>| (0..1001)
+            .map(|t| t as f64 / 1000.0)
+            .map(|t| Vector2d::from(bez3o.interp(t).unwrap() - curve.interp(t).unwrap()).len().powi(2))
+            .sum::<f64>();
+        let nudged = Bez2o::new(quad[0], quad[1] + Point2d::new(0.01, 0.0), quad[2]);
+        assert!(squared_error(&quad) < squared_error(&nudged));
+
+        // `NBez`es can be reduced straight to any lower order.
+        let nbez: NBez<f64> = NBez::from_slice(bez3o.elevate().elevate().as_ref()).unwrap();
+        let (nreduced, error) = nbez.reduce_to(3).unwrap();
+        assert_eq!(3, nreduced.order());
+        assert!(error <= 0.000000001);
+        let (point, _) = nbez.reduce_to(0).unwrap();
+        assert_eq!(0, point.order());
+        assert!(nbez.reduce_to(6).is_none());
+        assert_eq!(4, nbez.reduce().0.order());
+
+        let (line, error): (Bez<f64, f64, 0>, f64) = Bez1o::new(0.0, 2.0).reduce();
+        assert_eq!(1.0, line[0]);
+        assert_eq!(1.0, error);
+    }
+
+    #[test]
+    fn curvature() {
+        // A cubic with evenly-spaced control points on a line has a constant first derivative.
+        let line = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 2.0), Point2d::new(3.0, 3.0));
+        assert_eq!(Some(Vector2d::new(0.0, 0.0)), line.second_derivative(0.5));
+        assert_eq!(Some(0.0), line.curvature(0.5));
+        assert_eq!(Some(f64::INFINITY), line.radius_of_curvature(0.5));
+
+        // y = x^2, which has a curvature of 2 / (1 + 4x^2)^(3/2)
+        let parabola: Bez2o<f64> = Bez2o::new(Point2d::new(-1.0, 1.0), Point2d::new(0.0, -1.0), Point2d::new(1.0, 1.0));
+        let nbez: NBez<f64> = NBez::from_slice(parabola.as_ref()).unwrap();
+        let mut t: f64 = 0.0;
+        while t <= 1.0 {
+            let x = t * 2.0 - 1.0;
+            let expected = 2.0 / (1.0 + 4.0 * x * x).powf(1.5);
+            assert!((parabola.curvature(t).unwrap() - expected).abs() <= 0.000000001);
+            assert!((parabola.signed_curvature(t).unwrap() - expected).abs() <= 0.000000001);
+            assert!((parabola.radius_of_curvature(t).unwrap() - 1.0 / expected).abs() <= 0.000000001);
+            assert!((nbez.curvature(t).unwrap() - expected).abs() <= 0.000000001);
+            assert_eq!(parabola.second_derivative(t), Some(Vector2d::new(0.0, 8.0)));
+            t += 1.0/30.0;
+        }
+
+        let reversed: Bez2o<f64> = Bez2o::new(parabola[2], parabola[1], parabola[0]);
+        assert!((reversed.signed_curvature(0.5).unwrap() + 2.0).abs() <= 0.000000001);
+        assert_eq!(None, parabola.curvature(1.5));
+
+        // A 3D curve lying flat on the xy plane has the same curvature as its 2D counterpart.
+        let flat: Bez3o<f64, Point3d<f64>> = Bez3o::new(Point3d::new(0.0, 0.0, 0.0), Point3d::new(1.0, 2.0, 0.0), Point3d::new(2.0, -1.0, 0.0), Point3d::new(3.0, 1.0, 0.0));
+        let flat2d: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 1.0));
+        assert!((flat.curvature(0.3).unwrap() - flat2d.signed_curvature(0.3).unwrap().abs()).abs() <= 0.000000001);
+
+        // The curve stops at its cusp, so the curvature isn't defined there, but it is everywhere else.
+        let cusp: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0), Point2d::new(1.0, 0.0));
+        assert_eq!(Some(Vector2d::new(0.0, 0.0)), cusp.slope(0.5));
+        assert_eq!(None, cusp.curvature(0.5));
+        assert_eq!(None, cusp.signed_curvature(0.5));
+        assert_eq!(None, cusp.radius_of_curvature(0.5));
+        assert!(cusp.curvature(0.4).unwrap().is_finite());
+    }
+
     fn test_interp_iter<B>(curve: &B)
             where B: BezCurve<f64, Point = f64> {
 
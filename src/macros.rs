@@ -186,7 +186,19 @@ macro_rules! n_pointvector {
         }
 
         impl<F: Float> PVOps<F> for $v_name<F> {}
+        impl<F: Float> Point<F> for $v_name<F> {
+            type Vector = $v_name<F>;
+        }
         impl<F: Float> Vector<F> for $v_name<F> {}
+        impl<F: Float> MetricVector<F> for $v_name<F> {
+            fn dot(self, other: $v_name<F>) -> F {
+                $(self.$field * other.$field +)+ F::from_f32(0.0).unwrap()
+            }
+
+            fn length(self) -> F {
+                self.len()
+            }
+        }
     }
 }
 
@@ -202,245 +214,155 @@ macro_rules! check_t_bounds {
 }
 
 
-/// Create an n-order bezier polynomial.
+/// Create a type alias for the `Bez` of the given order, along with a constructor that takes each of
+/// the curve's points as a seperate parameter and a struct with each of those points as a field, which
+/// the curve dereferences to.
 ///
-/// `$doc`: the documentation for the struct
+/// `$doc`: the documentation for the alias
 ///
 /// `$order`: the order of the curve
 ///
-/// `$sum`: the sum of all numbers between 1 and $order
-///
-/// `$name`: the name of the struct
-///
-/// `$field`: the name of the field for the various points on the polynomial
-///
-/// `$weight`: the weight of each of the fields
-///
-/// `$start`: the first field
+/// `$name`: the name of the alias
 ///
-/// `$left`, `$right`: a pair of adjacent fields. Used for slope and curve elevation
+/// `$points`: the name of the struct holding the curve's points
 ///
-/// `$end`: the last field
+/// `$field`: the name of the parameter for the various points on the polynomial
 macro_rules! n_bezier {
-    ($doc:expr, $order:expr, $sum:expr; $name:ident {
-        $($field:ident: $weight:expr),+
-    } {
-        $start:ident;
-        $($left:ident, $right:ident: $dweight:expr),+;
-        $end:ident;
-    } elevated $elevated:ident<$($est:ty),+>) => {
-        #[derive(Debug, Clone, Copy)]
+    ($doc:expr, $order:expr; $name:ident, $points:ident {
+        $($field:ident),+
+    }) => {
         #[doc=$doc]
-        pub struct $name<F, P = $crate::Point2d<F>>
-                where F: $crate::Float,
-                      P: $crate::Point<F> {
-            $(pub $field: P),+,
-            __marker: std::marker::PhantomData<F>
+        pub type $name<F, P = $crate::Point2d<F>> = $crate::Bez<F, P, $order>;
+
+        /// The points of a curve, as similarly-named fields. The curve dereferences to this, so that
+        /// its points can be accessed by name.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $points<P> {
+            $(pub $field: P),+
         }
 
-        impl<F, P> $name<F, P>
+        impl<F, P> $crate::Bez<F, P, $order>
                 where F: $crate::Float,
                       P: $crate::Point<F> {
             /// Create a new bezier curve, with each field of this function corresponding to the similarly-named
             /// point on the curve.
-            pub fn new($($field: P),+) -> $name<F, P> {
-                $name {
-                    $($field: $field),+,
-                    __marker: std::marker::PhantomData
-                }
+            pub fn new($($field: P),+) -> $crate::Bez<F, P, $order> {
+                $crate::Bez::from_slice_static(&[$($field),+]).unwrap()
             }
         }
 
-        impl<F, P> $crate::BezCurve<F> for $name<F, P>
-                where P: $crate::Point<F>,
-                      F: $crate::Float {
-            type Point = P;
-            type Elevated = $elevated<$($est),+>;
-
-            fn from_slice(slice: &[P]) -> Option<$name<F, P>> {
-                use $crate::OrderStatic;
-                if slice.len() - 1 != $name::<F, P>::order_static() {
-                    None
-                } else {
-                    let mut index = -1;
-                    Some($name {$($field: {
-                        index += 1;
-                        slice[index as usize]
-                    }),+,
-                    __marker: std::marker::PhantomData})
-                }
-            }
-
-            fn interp_unbounded(&self, t: F) -> P {
-                let t1 = F::from_f32(1.0).unwrap() - t;
-                const COUNT: i32 = $order;
-                let mut factor = COUNT + 1;
-
-                $(
-                    factor -= 1;
-                    let $field =
-                        self.$field *
-                        t1.powi(factor) * 
-                        t.powi(COUNT-factor) *  
-                        F::from_i32($weight).unwrap();
-                )+
-                $($field +)+ P::zero()
-            }
-
-            fn slope_unbounded(&self, t: F) -> P::Vector {
-                let t1 = F::from_f32(1.0).unwrap() - t;
-                const COUNT: i32 = $order - 1;
-                let mut factor = COUNT + 1;
-
-                $(
-                    factor -= 1;
-                    let $right =
-                        (self.$right - self.$left) *
-                        t1.powi(factor) *
-                        t.powi(COUNT-factor) *
-                        F::from_i32($dweight * (COUNT + 1)).unwrap();
-                )+
-                ($($right +)+ P::zero()).into()
-            }
-
-            fn elevate(&self) -> $elevated<$($est),+> {
-                let mut factor = 0.0;
-                let order = F::from_usize(self.order() + 1).unwrap();
-                $elevated::from([self.$start, 
-                    $({
-                        factor += 1.0;
-                        let factor = F::from_f32(factor).unwrap();
-                        (self.$left * factor +
-                        self.$right * (order - factor)) / order
-                    },)+
-                    self.$end])
-            }
-
-            fn split_unbounded(&self, t: F) -> ($name<F, P>, $name<F, P>) {
-                use $crate::lerp;
-
-
-                if $order == 1 {
-                    let interp = lerp(self.$start, self.$end, t);
-                    (
-                        $name::from_slice([self.$start, interp].as_ref()).unwrap(),
-                        $name::from_slice([interp, self.$end].as_ref()).unwrap()
-                    )
-                } else {
-                    // `self`'s points as a slice
-                    let pslice = self.as_ref();
-
-                    const LERP_LEN: usize = $sum;
-                    let mut lerps = [P::zero(); LERP_LEN];
-
-                    // Populate `lerps` with linear interpolations of points and other elements of lerps.
-                    //
-                    // What that means is that the first few indices will be filled with direct interpolations of
-                    // points in the curve, and subsequent indices will interpolate between those points, and so on.
-                    // For example, with the fourth-order polynomial containing these points:
-                    //
-                    // [0.0, 1.0, 0.5, 1.0, 0.0]
-                    // 
-                    // The first four indicies of `lerps` will contain interpolations between those points, like so
-                    // (if splitting the curve at t = 0.5):
-                    //
-                    // 0.0 & 1.0    1.0 & 0.5    0.5 & 1.0    1.0 & 0.0
-                    // [  0.5,         0.75,        0.75,        0.5    ...
-                    //
-                    // The next three indicies will be interpolations between *those* points, like so:
-                    //
-                    //     0.5 & 0.75    0.75 & 0.75    0.75 & 0.5
-                    // ...    0.625,         0.75,          0.625  ...
-                    // 
-                    // The next two interpolate between the above three points, and the last point interpolates between
-                    // those two.
-                    let mut offset = $order;
-                    let mut offset_threshold = $order * 2 - 1;
-                    for i in 0..LERP_LEN {
-                        if i < $order {
-                            lerps[i] = lerp(pslice[i], pslice[i+1], t);
-                        } else {
-                            if i >= offset_threshold {
-                                offset -= 1;
-                                offset_threshold += offset - 1;
-                            }
-                            lerps[i] = lerp(lerps[i - offset], lerps[i - offset + 1], t);
-                        }
-                    }
-
-                    // The length of the `points` array
-                    const POINTS_LEN: usize = $order * 2 + 1;
-
-                    // An array containing the control points that compose the split curves, with one point of overlap
-                    // where the curves meet.
-                    let mut points = [P::zero(); POINTS_LEN];
-                    points[0] = self.$start;
-                    points[$order] = lerps[LERP_LEN - 1];
-                    points[POINTS_LEN - 1] = self.$end;
-
-
-                    // The index in `lerps` that we're going to be accessing for `points`
-                    let mut lerp_index = 0;
-
-                    // Move the lerped points from `lerp` to `points`
-                    for i in 1..$order {
-                        points[i] = lerps[lerp_index];
-                        points[POINTS_LEN - 1 - i] = lerps[lerp_index + $order - i];
-                        
-                        lerp_index += $order - i + 1;
-                    }
-
-
-                    (
-                        $name::from_slice(&points[..$order + 1]).expect("left poly fail"),
-                        $name::from_slice(&points[$order..]).expect("right poly fail")
-                    )
-                }
-            }
+        impl<F, P> ::std::ops::Deref for $crate::Bez<F, P, $order>
+                where F: $crate::Float,
+                      P: $crate::Point<F> {
+            type Target = $points<P>;
 
-            fn order(&self) -> usize {
-                use $crate::OrderStatic;
-                $name::<F, P>::order_static()
+            fn deref(&self) -> &$points<P> {
+                // Both `Bez` and the points struct are `repr(C)`, with `$order + 1` points in a row.
+                unsafe { &*(self as *const $crate::Bez<F, P, $order> as *const $points<P>) }
             }
         }
 
-        impl<F, P> $crate::OrderStatic for $name<F, P> 
+        impl<F, P> ::std::ops::DerefMut for $crate::Bez<F, P, $order>
                 where F: $crate::Float,
                       P: $crate::Point<F> {
-            #[inline]
-            fn order_static() -> usize {
-                $order
+            fn deref_mut(&mut self) -> &mut $points<P> {
+                unsafe { &mut *(self as *mut $crate::Bez<F, P, $order> as *mut $points<P>) }
             }
         }
 
-        impl<F, P> ::std::convert::From<[P; $order + 1]> for $name<F, P> 
+        impl<F, P> ::std::convert::From<[P; $order + 1]> for $crate::Bez<F, P, $order>
                 where F: $crate::Float,
                       P: $crate::Point<F> {
-            fn from(array: [P; $order + 1]) -> $name<F, P> {
-                use $crate::BezCurve;
-                $name::from_slice(&array[..]).unwrap()
+            fn from(array: [P; $order + 1]) -> $crate::Bez<F, P, $order> {
+                $crate::Bez::from_slice_static(&array[..]).unwrap()
             }
         }
+    }
+}
 
-        impl<F, P> ::std::convert::AsRef<[P]> for $name<F, P> 
+/// Implement `BezCurve` for the `Bez` of the given order. This can't be done with a single generic
+/// impl, as the types of the elevated, derivative and reduced curves depend on `$order + 1` and
+/// `$order - 1`, which stable Rust can't express in terms of a generic order.
+///
+/// `$order`: the order of the curve
+///
+/// `$elevated`: the type of the elevated curve
+///
+/// `$derivative`: the type of the derivative curve
+///
+/// `$reduced`: the type of the reduced curve
+macro_rules! bez_curve {
+    ($order:expr; elevated $elevated:ty; derivative $derivative:ty; reduced $reduced:ty) => {
+        impl<F, P> $crate::BezCurve<F> for $crate::Bez<F, P, $order>
                 where F: $crate::Float,
                       P: $crate::Point<F> {
-            fn as_ref(&self) -> &[P] {
-                use std::slice;
-                unsafe {
-                    slice::from_raw_parts(self as *const $name<F, P> as *const P, $order + 1)
-                }
+            type Point = P;
+            type Elevated = $elevated;
+            type Derivative = $derivative;
+            type Reduced = $reduced;
+
+            #[inline]
+            fn points(&self) -> ::std::borrow::Cow<'_, [P]> {
+                ::std::borrow::Cow::Borrowed(self.as_ref())
+            }
+
+            #[inline]
+            fn interp_unbounded(&self, t: F) -> P {
+                self.interp_static(t)
+            }
+
+            #[inline]
+            fn slope_unbounded(&self, t: F) -> P::Vector {
+                self.slope_static(t)
+            }
+
+            fn elevate(&self) -> $elevated {
+                use $crate::OwnedBezCurve;
+                <$elevated>::from_slice(self.elevate_static::<{ $order + 1 }>().as_ref()).unwrap()
+            }
+
+            /// Get the curve's derivative, which has the differences between adjacent points scaled by
+            /// the order as its points. The derivative of a curve of order `0` is a single zero vector.
+            #[inline]
+            fn derivative(&self) -> $derivative {
+                self.derivative_static()
+            }
+
+            #[inline]
+            fn reduce(&self) -> ($reduced, F)
+                    where P::Vector: $crate::MetricVector<F> {
+                self.reduce_static()
+            }
+
+            #[inline]
+            fn order(&self) -> usize {
+                $order
             }
         }
+    }
+}
 
-        impl<F, P> ::std::convert::AsMut<[P]> for $name<F, P> 
+/// Create a type alias for the `RBez` of the given order, along with a constructor that takes the
+/// curve's weights as an array.
+///
+/// `$doc`: the documentation for the alias
+///
+/// `$order`: the order of the curve
+///
+/// `$name`: the name of the alias
+macro_rules! n_rbezier {
+    ($doc:expr, $order:expr; $name:ident) => {
+        #[doc=$doc]
+        pub type $name<F, P = $crate::Point2d<F>> = $crate::RBez<F, P, $order>;
+
+        impl<F, P> $crate::RBez<F, P, $order>
                 where F: $crate::Float,
-                      P: $crate::Point<F> {
-            fn as_mut(&mut self) -> &mut [P] {
-                use std::slice;
-                unsafe {
-                    slice::from_raw_parts_mut(self as *mut $name<F, P> as *mut P, $order + 1)
-                }
+                      P: $crate::Homogeneous<F> {
+            /// Create a new rational curve out of `curve`'s points, with each point getting the weight
+            /// at the same index in `weights`.
+            pub fn new(curve: $crate::Bez<F, P, $order>, weights: [F; $order + 1]) -> $crate::RBez<F, P, $order> {
+                $crate::RBez::from_slices_static(curve.as_ref(), &weights).unwrap()
             }
         }
     }
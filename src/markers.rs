@@ -35,19 +35,52 @@ pub trait Point<F>:
 }
 
 /// A vector. Gets associated with any number of points
+///
+/// Every vector is also a point, with itself as its vector type. This lets curves be made out of
+/// vectors, such as the curve returned by `BezCurve::derivative`.
 pub trait Vector<F: Float>: 
 		Clone +
 		Copy +
-		PVOps<F> {}
+		PVOps<F> +
+		Point<F, Vector = Self> {}
+
+/// A vector in euclidean space, which has a length and can be projected onto other vectors
+pub trait MetricVector<F: Float>: Vector<F> {
+	/// Get the dot product of `self` and `other`
+	fn dot(self, other: Self) -> F;
+
+	/// Get the length of this vector
+	fn length(self) -> F {
+		self.dot(self).sqrt()
+	}
+}
 
 impl PVOps<f32> for f32 {}
 impl Point<f32> for f32 {
 	type Vector = f32;
 }
 impl Vector<f32> for f32 {}
+impl MetricVector<f32> for f32 {
+	fn dot(self, other: f32) -> f32 {
+		self * other
+	}
+
+	fn length(self) -> f32 {
+		self.abs()
+	}
+}
 
 impl PVOps<f64> for f64 {}
 impl Point<f64> for f64 {
 	type Vector = f64;
 }
-impl Vector<f64> for f64 {}
\ No newline at end of file
+impl Vector<f64> for f64 {}
+impl MetricVector<f64> for f64 {
+	fn dot(self, other: f64) -> f64 {
+		self * other
+	}
+
+	fn length(self) -> f64 {
+		self.abs()
+	}
+}
\ No newline at end of file
@@ -1,96 +1,126 @@
+use std::borrow::Cow;
 use std::convert::{AsRef, AsMut, From};
-use std::cell::{Cell, RefCell};
 use std::marker::PhantomData;
 use std::fmt::{Debug, Formatter};
-use std::ops::Range;
+use std::sync::OnceLock;
 
 
-use super::{BezCurve, Point2d, Float, Point, lerp};
+use super::{BezCurve, OwnedBezCurve, OrderStatic, Point2d, Float, Point, PVOps, MetricVector, lerp, de_casteljau, de_casteljau_split, reduce};
 
-/// A struct that contains range information for slicing, used for slicing into the global factor
-/// vector. The reason this is used instead of stdlib's `Range` struct is that `Range` does not
-/// implement Copy, which means we have to use `RefCell`s instead of `Cell`s for interior mutability.
-#[derive(Copy, Clone)]
-struct RangeSlice {
-    start: usize,
-    end: usize
+/// The highest order that `bernstein_horner` can evaluate with `F`. The binomial factors of order `n`
+/// sum to `2^n`, so capping `n` at half of `F`'s maximum exponent leaves the other half as headroom
+/// for the magnitude of the points. Curves of higher orders are evaluated with de Casteljau's
+/// algorithm instead.
+fn horner_max_order<F: Float>() -> usize {
+    (F::max_value().log2() / F::from_f32(2.0).unwrap()).to_usize().unwrap()
 }
 
-impl RangeSlice {
-    #[inline]
-    fn new(start: usize, end: usize) -> RangeSlice {
-        RangeSlice {
-            start: start,
-            end: end
+/// Create a vector containing the factors of the given order, followed by the factors of the order's
+/// derivative.
+///
+/// The factors are the binomial coefficients of the order, built up row-by-row with Pascal's triangle.
+/// Because only additions are involved they don't overflow the way factorials do, and they stay exact
+/// for as long as `F` can represent them.
+fn binomial_factors<F: Float>(order: usize) -> Vec<F> {
+    // The vector stores both the factors of the order and the order's derivative, and this is the
+    // length necessary to contain those factors.
+    let mut vec = vec![F::zero(); (order + 1) * 2 - 1];
+
+    {
+        let (row, drow) = vec.split_at_mut(order + 1);
+
+        // `row` holds the current row of Pascal's triangle, which gets updated in place.
+        row[0] = F::one();
+        for n in 1..order + 1 {
+            // The derivative's factors are the row right before the order's.
+            if n == order {
+                drow.copy_from_slice(&row[..order]);
+            }
+
+            for k in (1..n + 1).rev() {
+                row[k] = row[k] + row[k - 1];
+            }
         }
     }
 
-    fn as_range(&self) -> Range<usize> {
-        self.start..self.end
-    }
+    vec
+}
 
-    fn len(&self) -> usize {
-        self.end - self.start
+/// Evaluate the bernstein polynomial `sum(factors[k] * point(k) * t^k * (1 - t)^(n - k))`, where `n`
+/// is `factors.len() - 1`.
+///
+/// Rather than calculating each power seperately, which loses precision and eventually overflows at
+/// high orders, this uses Horner's method on the polynomial divided by `(1 - t)^n`. That leaves a
+/// polynomial in `t / (1 - t)`, which stays within `[0, 1]` for `t` in `[0, 0.5]`. For `t` past
+/// `0.5`, the polynomial is divided by `t^n` instead and evaluated in `(1 - t) / t`.
+fn bernstein_horner<F, P, G>(t: F, factors: &[F], point: G) -> P
+        where F: Float,
+              P: PVOps<F>,
+              G: Fn(usize) -> P {
+    let one = F::one();
+    let n = factors.len() - 1;
+
+    if t <= F::from_f32(0.5).unwrap() {
+        let t1 = one - t;
+        let s = t / t1;
+        let mut acc = point(n) * factors[n];
+        for k in (0..n).rev() {
+            acc = acc * s + point(k) * factors[k];
+        }
+        acc * t1.powi(n as i32)
+    } else {
+        let s = (one - t) / t;
+        let mut acc = point(0) * factors[0];
+        for (k, factor) in factors.iter().enumerate().skip(1) {
+            acc = acc * s + point(k) * *factor;
+        }
+        acc * t.powi(n as i32)
     }
 }
 
-fn combination(n: u64, k: u64) -> u64 {
-    factorial(n) / (factorial(k) * factorial(n - k))
+/// A container of points that new instances can be created of, given a slice of points. `NBez` needs
+/// this to build curves of its own type, which is required for it to be an `OwnedBezCurve`.
+pub trait PointContainer<P>: AsRef<[P]> + AsMut<[P]> + Clone {
+    /// Attempt to create a container holding a copy of the slice's points. Fails if the container
+    /// can't hold that number of points.
+    fn from_point_slice(slice: &[P]) -> Option<Self>;
 }
 
-fn factorial(mut n: u64) -> u64 {
-    let mut accumulator: u64 = 1;
-    while n > 0 {
-        accumulator = accumulator.checked_mul(n).expect("Attempted to create Bézier curve with combination that overflow u64; decrease curve order");
-        n -= 1;
+impl<P: Copy> PointContainer<P> for Vec<P> {
+    fn from_point_slice(slice: &[P]) -> Option<Vec<P>> {
+        Some(slice.to_vec())
     }
-    accumulator
 }
 
-/// Given the `order` and references to the `factors`, `dfactors`, and `vec` cells, update the
-/// cells to contain accurate information about the factors of the order. 
-fn update_factors(order: usize, factors: &Cell<RangeSlice>, dfactors: &Cell<RangeSlice>, vec: &RefCell<Vec<u64>>) {
-    if factors.get().len() != order + 1 {
-        let mut vec = vec.borrow_mut();
-        // Remove everything from the vector without freeing memory
-        unsafe{ vec.set_len(0) };
-
-        // The vector stores both the factors of the order and the order's derivative, and this is the
-        // length necessary to contain those factors.
-        let new_len = (order + 1) * 2 - 1;
-        if vec.capacity() < new_len {
-            let reserve_amount = new_len - vec.capacity();
-            vec.reserve(reserve_amount);
-        }
-
-        {
-            let order = order as u64;
-
-            for k in 0..order + 1 {
-                vec.push(combination(order, k));
-            }
+impl<P: Copy> PointContainer<P> for Box<[P]> {
+    fn from_point_slice(slice: &[P]) -> Option<Box<[P]>> {
+        Some(slice.to_vec().into_boxed_slice())
+    }
+}
 
-            for k in 0..order {
-                vec.push(combination(order - 1, k));
-            }
+impl<P: Copy, const N: usize> PointContainer<P> for [P; N] {
+    fn from_point_slice(slice: &[P]) -> Option<[P; N]> {
+        if slice.len() != N {
+            None
+        } else {
+            let mut array = [slice[0]; N];
+            array.copy_from_slice(slice);
+            Some(array)
         }
-
-        factors.set(RangeSlice::new(0, order + 1));
-        dfactors.set(RangeSlice::new(order + 1, vec.len()));
     }
 }
 
-
-/// An n-order bezier curve. The `from_slice`, `split`, and `split_unbounded` functions currently do not work.
+/// An n-order bezier curve.
 #[derive(Clone)]
 pub struct NBez<F, P = Point2d<F>, C = Vec<P>> 
         where F: Float,
               P: Point<F>,
               C: AsRef<[P]> + AsMut<[P]> {
     points: C,
-    factor_vec: RefCell<Vec<u64>>,
-    factors: Cell<RangeSlice>,
-    dfactors: Cell<RangeSlice>,
+    /// The factors of the curve's order and its derivative's order, as returned by `binomial_factors`.
+    /// They get calculated the first time they're needed, and are cleared whenever the container is
+    /// borrowed mutably, as that's the only way the order can change.
+    factors: OnceLock<Vec<F>>,
     phantom: PhantomData<(F, P)>
 }
 
@@ -109,15 +139,9 @@ impl<F, P, C> NBez<F, P, C>
               C: AsRef<[P]> + AsMut<[P]> {
     #[inline]
     pub fn from_container(points: C) -> NBez<F, P, C> {
-        if points.as_ref().len() >= 22 {
-            panic!("Cannot create Bézier polynomials with an order >= 21")
-        }
-
         NBez {
             points: points,
-            factor_vec: RefCell::new(Vec::new()),
-            factors: Cell::new(RangeSlice::new(0, 0)),
-            dfactors: Cell::new(RangeSlice::new(0, 0)),
+            factors: OnceLock::new(),
             phantom: PhantomData
         }
     }
@@ -126,6 +150,28 @@ impl<F, P, C> NBez<F, P, C>
     pub fn unwrap(self) -> C {
         self.points
     }
+
+    /// Reduce the curve to the given order, getting the curve of that order that best fits this one
+    /// along with an upper bound on the distance between the two curves. Returns `None` if `order` is
+    /// higher than the curve's order. See `BezCurve::reduce` for how the curve is fit.
+    pub fn reduce_to(&self, order: usize) -> Option<(NBez<F, P>, F)>
+            where P::Vector: MetricVector<F> {
+        let points = self.points.as_ref();
+        if order + 1 > points.len() {
+            return None;
+        }
+
+        let reduced = reduce::reduce_points(points, order);
+        let error = reduce::error_bound(points, &reduced);
+        Some((NBez::from_container(reduced), error))
+    }
+
+    /// Get the factors of the curve's order and the factors of its derivative's order, calculating
+    /// them if they aren't already cached.
+    fn factors(&self) -> (&[F], &[F]) {
+        let order = self.points.as_ref().len() - 1;
+        self.factors.get_or_init(|| binomial_factors(order)).split_at(order + 1)
+    }
 }
 
 impl<F, P, C> BezCurve<F> for NBez<F, P, C> 
@@ -134,53 +180,40 @@ impl<F, P, C> BezCurve<F> for NBez<F, P, C>
               C: AsRef<[P]> + AsMut<[P]> {
     type Point = P;
     type Elevated = NBez<F, P, Vec<P>>;
+    type Derivative = NBez<F, P::Vector, Vec<P::Vector>>;
+    type Reduced = NBez<F, P, Vec<P>>;
 
-    /// Currently non-functional; returns `None`
-    fn from_slice(_: &[P]) -> Option<NBez<F, P, C>> {
-        None
+    #[inline]
+    fn points(&self) -> Cow<'_, [P]> {
+        Cow::Borrowed(self.points.as_ref())
     }
 
     fn interp_unbounded(&self, t: F) -> P {
         let points = self.points.as_ref();
-        update_factors(self.order(), &self.factors, &self.dfactors, &self.factor_vec);
-        let factors = &self.factor_vec.borrow()[self.factors.get().as_range()];
-
+        if self.order() > horner_max_order::<F>() {
+            return de_casteljau(&mut points.to_vec(), t);
+        }
 
-        let t1 = F::from_f32(1.0).unwrap() - t;
-        let order = factors.len() - 1;
-        let mut acc = P::zero();
-        let mut factor = 0;
+        let (factors, _) = self.factors();
 
-        for point in points.iter() {
-            acc = acc + *point * 
-                        t.powi(factor as i32) *
-                        t1.powi((order - factor) as i32) *
-                        F::from_u64(factors[factor]).unwrap();
-            factor += 1;
-        }            
-        acc
+        bernstein_horner(t, factors, |k| points[k])
     }
 
     fn slope_unbounded(&self, t: F) -> P::Vector {
         let points = self.points.as_ref();
-        update_factors(self.order(), &self.factors, &self.dfactors, &self.factor_vec);
-        let dfactors = &self.factor_vec.borrow()[self.dfactors.get().as_range()];
-
-        let t1 = F::from_f32(1.0).unwrap() - t;
-        let order = dfactors.len() - 1;
-        let mut acc = P::zero();
-        let mut factor = 0;
-        let mut point_last = points[0].clone();
-
-        for point in points[1..].iter().map(|p| *p) {
-            acc = acc + (point - point_last) *
-                        t.powi(factor as i32) *
-                        t1.powi((order-factor) as i32) *
-                        F::from_u64(dfactors[factor] * (order + 1) as u64).unwrap();
-            point_last = point;
-            factor += 1;
-        }            
-        acc.into()
+        let order = self.order();
+        if 0 == order {
+            return P::zero().into();
+        }
+
+        let slope = if order > horner_max_order::<F>() {
+            let mut diffs: Vec<P> = points.windows(2).map(|pair| pair[1] - pair[0]).collect();
+            de_casteljau(&mut diffs, t)
+        } else {
+            let (_, dfactors) = self.factors();
+            bernstein_horner(t, dfactors, |k| points[k + 1] - points[k])
+        };
+        (slope * F::from_usize(order).unwrap()).into()
     }
 
     fn elevate(&self) -> NBez<F, P, Vec<P>> {        
@@ -203,14 +236,19 @@ impl<F, P, C> BezCurve<F> for NBez<F, P, C>
         NBez::from_container(el_points)
     }
 
-    /// Currently non-functional; returns `None`
-    fn split(&self, _: F) -> Option<(NBez<F, P, C>, NBez<F, P, C>)> {
-        None
-    }
+    /// Get the curve's derivative, which is backed by a `Vec` regardless of the curve's container. The
+    /// derivative of a curve of order `0` is a single zero vector.
+    fn derivative(&self) -> NBez<F, P::Vector, Vec<P::Vector>> {
+        let points = self.points.as_ref();
+        if 0 == self.order() {
+            return NBez::from_container(vec![P::zero().into()]);
+        }
 
-    /// Currently non-functional; panics with unimplemented
-    fn split_unbounded(&self, _: F) -> (NBez<F, P, C>, NBez<F, P, C>) {
-        unimplemented!()
+        let order = F::from_usize(self.order()).unwrap();
+        let dpoints = points.windows(2)
+            .map(|pair| (pair[1] - pair[0]).into() * order)
+            .collect();
+        NBez::from_container(dpoints)
     }
 
     fn order(&self) -> usize {
@@ -218,6 +256,49 @@ impl<F, P, C> BezCurve<F> for NBez<F, P, C>
     }
 }
 
+/// Creating new curves needs new containers, so only `NBez`es backed by a `PointContainer` can be
+/// split and the like.
+impl<F, P, C> OwnedBezCurve<F> for NBez<F, P, C>
+        where F: Float,
+              P: Point<F>,
+              C: PointContainer<P> {
+    /// Create a curve from a slice. Fails if the slice is empty, or if the container can't hold the
+    /// slice's points.
+    fn from_slice(slice: &[P]) -> Option<NBez<F, P, C>> {
+        if slice.is_empty() {
+            None
+        } else {
+            C::from_point_slice(slice).map(NBez::from_container)
+        }
+    }
+
+    fn split_unbounded(&self, t: F) -> (NBez<F, P, C>, NBez<F, P, C>) {
+        let points = self.points.as_ref();
+
+        // The working buffer for de Casteljau's algorithm
+        let mut lerps = points.to_vec();
+
+        // The curves are created by cloning `self` and overwriting the points, as that's the only way
+        // to get new containers of type `C`.
+        let mut left = self.clone();
+        let mut right = self.clone();
+        de_casteljau_split(points, t, &mut lerps, left.points.as_mut(), right.points.as_mut());
+
+        (left, right)
+    }
+}
+
+/// `NBez`es backed by arrays have their order known at compiletime, which lets them be used in a
+/// `BezChain`.
+impl<F, P, const N: usize> OrderStatic for NBez<F, P, [P; N]>
+        where F: Float,
+              P: Point<F> {
+    #[inline]
+    fn order_static() -> usize {
+        N - 1
+    }
+}
+
 impl<F, P, C> AsRef<C> for NBez<F, P, C>
         where F: Float,
               P: Point<F>,
@@ -232,6 +313,8 @@ impl<F, P, C> AsMut<C> for NBez<F, P, C>
               P: Point<F>,
               C: AsRef<[P]> + AsMut<[P]> {
     fn as_mut(&mut self) -> &mut C {
+        // The container may get resized, so the factors have to be recalculated.
+        self.factors.take();
         &mut self.points
     }
 }
@@ -264,3 +347,340 @@ impl<F, P, C> Debug for NBez<F, P, C>
             .finish()
     }
 }
+
+
+/// A chain of n-order bezier curves, with the last point of each curve being the first point of the
+/// next. Unlike `BezChain`, the order of the curves is set at runtime, but it's still shared by every
+/// curve in the chain.
+#[derive(Clone, Copy)]
+pub struct NBezChain<F, P = Point2d<F>, C = Vec<P>>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]> {
+    points: C,
+    order: usize,
+    phantom: PhantomData<(F, P)>
+}
+
+impl<F, P, C> NBezChain<F, P, C>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]> {
+    /// Create a new NBezChain by wrapping around a container, with each curve in the chain having the
+    /// given order. Panics if `order` is zero.
+    #[inline]
+    pub fn from_container(container: C, order: usize) -> NBezChain<F, P, C> {
+        if 0 == order {
+            panic!("Cannot create a chain of order 0 Bézier curves")
+        }
+
+        NBezChain {
+            points: container,
+            order,
+            phantom: PhantomData
+        }
+    }
+
+    /// Get the bezier curve that is `index` curves away from the start. Returns `None` if not enough
+    /// points exist for the given curve index.
+    pub fn get(&self, index: usize) -> Option<NBez<F, P, Vec<P>>> {
+        let points = self.points.as_ref();
+        let curve_index = index * self.order;
+        let curve_end_index = curve_index + self.order + 1;
+
+        if curve_end_index > points.len() {
+            None
+        } else {
+            Some(NBez::from_container(points[curve_index..curve_end_index].to_vec()))
+        }
+    }
+
+    /// Get an iterator over all curves in the chain.
+    #[inline]
+    pub fn iter(&self) -> NBezIter<'_, F, P> {
+        let points = self.points.as_ref();
+
+        // Any control points that can't be used to create a full curve get cut off here, so that the
+        // iterator doesn't have to worry about them.
+        let len = if points.is_empty() {
+            0
+        } else {
+            points.len() - (points.len() - 1) % self.order
+        };
+
+        NBezIter {
+            points: &points[..len],
+            order: self.order,
+            phantom: PhantomData
+        }
+    }
+
+    /// Get the order of the chain's curves.
+    #[inline]
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Unwrap the chain, returning the underlying container.
+    #[inline]
+    pub fn unwrap(self) -> C {
+        self.points
+    }
+}
+
+impl<F, P, C> AsRef<C> for NBezChain<F, P, C>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]> {
+    fn as_ref(&self) -> &C {
+        &self.points
+    }
+}
+
+impl<F, P, C> AsMut<C> for NBezChain<F, P, C>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]> {
+    fn as_mut(&mut self) -> &mut C {
+        &mut self.points
+    }
+}
+
+impl<F, P, C> Debug for NBezChain<F, P, C>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]> + Debug {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), ::std::fmt::Error> {
+        f.debug_tuple("NBezChain")
+            .field(&self.points)
+            .field(&self.order)
+            .finish()
+    }
+}
+
+/// Iterator over n-order bezier curve chains
+pub struct NBezIter<'a, F, P>
+        where F: Float,
+              P: 'a + Point<F> {
+    /// The points that haven't been iterated over yet. Always contains a whole number of curves.
+    points: &'a [P],
+    order: usize,
+    phantom: PhantomData<F>
+}
+
+impl<'a, F, P> Iterator for NBezIter<'a, F, P>
+        where F: Float,
+              P: Point<F> {
+    type Item = NBez<F, P, Vec<P>>;
+    fn next(&mut self) -> Option<NBez<F, P, Vec<P>>> {
+        if self.points.len() <= self.order {
+            None
+        } else {
+            let curve = NBez::from_container(self.points[..self.order + 1].to_vec());
+            self.points = &self.points[self.order..];
+            Some(curve)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = if self.points.is_empty() {
+            0
+        } else {
+            (self.points.len() - 1) / self.order
+        };
+        (size, Some(size))
+    }
+}
+
+impl<'a, F, P> DoubleEndedIterator for NBezIter<'a, F, P>
+        where F: Float,
+              P: Point<F> {
+    fn next_back(&mut self) -> Option<NBez<F, P, Vec<P>>> {
+        let len = self.points.len();
+
+        if len <= self.order {
+            None
+        } else {
+            let curve = NBez::from_container(self.points[len - self.order - 1..].to_vec());
+            self.points = &self.points[..len - self.order];
+            Some(curve)
+        }
+    }
+}
+
+impl<'a, F, P> ExactSizeIterator for NBezIter<'a, F, P>
+        where F: Float,
+              P: Point<F> {}
+
+
+/// A chain of n-order bezier curves, with the last point of each curve being the first point of the
+/// next. Each curve in the chain has its own order, stored in a second container.
+#[derive(Clone, Copy)]
+pub struct NBezVarChain<F, P = Point2d<F>, C = Vec<P>, O = Vec<usize>>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]>,
+              O: AsRef<[usize]> {
+    points: C,
+    orders: O,
+    phantom: PhantomData<(F, P)>
+}
+
+impl<F, P, C, O> NBezVarChain<F, P, C, O>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]>,
+              O: AsRef<[usize]> {
+    /// Create a new NBezVarChain by wrapping around a container of points and a container of curve
+    /// orders, where the nth order is the order of the nth curve in the chain.
+    #[inline]
+    pub fn from_containers(points: C, orders: O) -> NBezVarChain<F, P, C, O> {
+        NBezVarChain {
+            points,
+            orders,
+            phantom: PhantomData
+        }
+    }
+
+    /// Get the bezier curve that is `index` curves away from the start. Returns `None` if there is no
+    /// order for the given curve index, or if not enough points exist for the curve.
+    pub fn get(&self, index: usize) -> Option<NBez<F, P, Vec<P>>> {
+        let points = self.points.as_ref();
+        let orders = self.orders.as_ref();
+
+        if index >= orders.len() {
+            None
+        } else {
+            let curve_index: usize = orders[..index].iter().sum();
+            let curve_end_index = curve_index + orders[index] + 1;
+
+            if curve_end_index > points.len() {
+                None
+            } else {
+                Some(NBez::from_container(points[curve_index..curve_end_index].to_vec()))
+            }
+        }
+    }
+
+    /// Get an iterator over all curves in the chain.
+    pub fn iter(&self) -> NBezVarIter<'_, F, P> {
+        let points = self.points.as_ref();
+        let orders = self.orders.as_ref();
+
+        // Cut off any orders that there aren't enough points to create a curve for, as well as any
+        // points that don't belong to a curve.
+        let mut len = 0;
+        let mut curves = 0;
+        for order in orders {
+            if points.len() < len + order + 1 {
+                break;
+            }
+            len += *order;
+            curves += 1;
+        }
+
+        NBezVarIter {
+            points: &points[..if curves == 0 {0} else {len + 1}],
+            orders: &orders[..curves],
+            phantom: PhantomData
+        }
+    }
+
+    /// Get the orders of the chain's curves.
+    #[inline]
+    pub fn orders(&self) -> &[usize] {
+        self.orders.as_ref()
+    }
+
+    /// Unwrap the chain, returning the underlying point and order containers.
+    #[inline]
+    pub fn unwrap(self) -> (C, O) {
+        (self.points, self.orders)
+    }
+}
+
+impl<F, P, C, O> AsRef<C> for NBezVarChain<F, P, C, O>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]>,
+              O: AsRef<[usize]> {
+    fn as_ref(&self) -> &C {
+        &self.points
+    }
+}
+
+impl<F, P, C, O> AsMut<C> for NBezVarChain<F, P, C, O>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]>,
+              O: AsRef<[usize]> {
+    fn as_mut(&mut self) -> &mut C {
+        &mut self.points
+    }
+}
+
+impl<F, P, C, O> Debug for NBezVarChain<F, P, C, O>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]> + Debug,
+              O: AsRef<[usize]> + Debug {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), ::std::fmt::Error> {
+        f.debug_tuple("NBezVarChain")
+            .field(&self.points)
+            .field(&self.orders)
+            .finish()
+    }
+}
+
+/// Iterator over variable-order bezier curve chains
+pub struct NBezVarIter<'a, F, P>
+        where F: Float,
+              P: 'a + Point<F> {
+    /// The points that haven't been iterated over yet. Always contains exactly the points needed for
+    /// the curves in `orders`.
+    points: &'a [P],
+    orders: &'a [usize],
+    phantom: PhantomData<F>
+}
+
+impl<'a, F, P> Iterator for NBezVarIter<'a, F, P>
+        where F: Float,
+              P: Point<F> {
+    type Item = NBez<F, P, Vec<P>>;
+    fn next(&mut self) -> Option<NBez<F, P, Vec<P>>> {
+        match self.orders.split_first() {
+            Some((&order, orders)) => {
+                let curve = NBez::from_container(self.points[..order + 1].to_vec());
+                self.points = &self.points[order..];
+                self.orders = orders;
+                Some(curve)
+            }
+            None => None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.orders.len(), Some(self.orders.len()))
+    }
+}
+
+impl<'a, F, P> DoubleEndedIterator for NBezVarIter<'a, F, P>
+        where F: Float,
+              P: Point<F> {
+    fn next_back(&mut self) -> Option<NBez<F, P, Vec<P>>> {
+        match self.orders.split_last() {
+            Some((&order, orders)) => {
+                let len = self.points.len();
+                let curve = NBez::from_container(self.points[len - order - 1..].to_vec());
+                self.points = &self.points[..len - order];
+                self.orders = orders;
+                Some(curve)
+            }
+            None => None
+        }
+    }
+}
+
+impl<'a, F, P> ExactSizeIterator for NBezVarIter<'a, F, P>
+        where F: Float,
+              P: Point<F> {}
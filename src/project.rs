@@ -0,0 +1,51 @@
+use super::{BezCurve, Float, Point, MetricVector};
+use super::solve::{bernstein_mul_with, bernstein_roots};
+
+/// Find the `t` on the curve that is closest to `point`, along with the distance between `point` and
+/// the curve at that `t`.
+///
+/// The closest point is either one of the curve's ends or a point where the vector from `point` to the
+/// curve is perpendicular to the curve's slope. The dot product of those vectors is a bernstein
+/// polynomial, so every candidate between the ends is one of its roots.
+pub(crate) fn project<F, B>(curve: &B, point: B::Point) -> (F, F)
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    let distance = |t: F| -> F {
+        let offset: <B::Point as Point<F>>::Vector = (curve.interp_unbounded(t) - point).into();
+        offset.length()
+    };
+
+    let mut closest = (F::zero(), distance(F::zero()));
+    let end_distance = distance(F::one());
+    if end_distance < closest.1 {
+        closest = (F::one(), end_distance);
+    }
+
+    if 0 == curve.order() {
+        return closest;
+    }
+
+    for t in bernstein_roots(&perpendicular_coeffs(curve, point)) {
+        let t_distance = distance(t);
+        if t_distance < closest.1 {
+            closest = (t, t_distance);
+        }
+    }
+
+    closest
+}
+
+/// Get the coefficients of a bernstein polynomial with the same roots and signs as the dot product of
+/// the vector from `point` to the curve and the curve's slope.
+///
+/// That's the product of the numerators of the curve offset by `point` and of its derivative. As long as
+/// a rational curve's weights are positive, its denominators don't affect the signs.
+fn perpendicular_coeffs<F, B>(curve: &B, point: B::Point) -> Vec<F>
+        where F: Float,
+              B: BezCurve<F>,
+              <B::Point as Point<F>>::Vector: MetricVector<F> {
+    let offsets = curve.numerator(|p| -> <B::Point as Point<F>>::Vector { (p - point).into() });
+    let dpoints = curve.derivative_numerator(|p| -> <B::Point as Point<F>>::Vector { p.into() });
+    bernstein_mul_with(&offsets, &dpoints, |a: <B::Point as Point<F>>::Vector, b| a.dot(b))
+}
@@ -0,0 +1,668 @@
+use std::borrow::Cow;
+use std::convert::{AsRef, AsMut, From};
+use std::fmt::{Debug, Formatter};
+use std::ops::{Add, Sub, Mul, Div};
+
+use num_traits::identities::Zero;
+
+use super::{Bez, BezCurve, OwnedBezCurve, NBez, PointContainer, Float, Linear, Point, Point2d, Point3d, Point4d, Vector2d,
+            Vector3d, Vector4d, MetricVector, Transform, ProjectiveTransform, de_casteljau, de_casteljau_split};
+use super::elevate::elevate_points;
+use super::reduce::{reduce_points, sampled_error};
+use super::solve::bernstein_mul;
+
+/// A point that can be lifted into homogeneous coordinates one dimension higher, which is how rational
+/// curves get evaluated. The last component of a homogeneous point is its weight, and the others are
+/// the point's components multiplied by that weight.
+///
+/// A homogeneous point with a weight of zero is a point at infinity, in the direction given by its other
+/// components. When a point at infinity is projected back down, it's represented by that direction, and
+/// lifting a point with a weight of zero treats the point as a direction in the same way, so the two
+/// stay inverses of each other.
+pub trait Homogeneous<F: Float>: Point<F> {
+    /// The homogeneous point type
+    type Lifted: Point<F>;
+
+    /// Split a homogeneous point into its weighted components and its weight, without dividing the
+    /// weight back out.
+    fn split_weight(lifted: Self::Lifted) -> (Self, F);
+
+    /// Join weighted components and their weight into a homogeneous point. The inverse of
+    /// `split_weight`.
+    fn join_weight(self, weight: F) -> Self::Lifted;
+
+    /// Lift the point into homogeneous coordinates with the given weight. A weight of zero lifts the
+    /// point as a direction.
+    fn lift(self, weight: F) -> Self::Lifted {
+        if weight == F::zero() {
+            self.join_weight(weight)
+        } else {
+            (self * weight).join_weight(weight)
+        }
+    }
+
+    /// Project a homogeneous point back down, returning the point and its weight. Points at infinity
+    /// are returned as their direction.
+    fn project(lifted: Self::Lifted) -> (Self, F) {
+        let (point, weight) = Self::split_weight(lifted);
+        if weight == F::zero() {
+            (point, weight)
+        } else {
+            (point / weight, weight)
+        }
+    }
+}
+
+macro_rules! homogeneous {
+    ($name:ident, $lifted:ident; $($field:ident),+; $weight:ident) => {
+        impl<F: Float> Homogeneous<F> for $name<F> {
+            type Lifted = $lifted<F>;
+
+            fn split_weight(lifted: $lifted<F>) -> ($name<F>, F) {
+                ($name { $($field: lifted.$field),+ }, lifted.$weight)
+            }
+
+            fn join_weight(self, weight: F) -> $lifted<F> {
+                $lifted {
+                    $($field: self.$field,)+
+                    $weight: weight
+                }
+            }
+        }
+    }
+}
+
+homogeneous!{Point2d, Point3d; x, y; z}
+homogeneous!{Vector2d, Vector3d; x, y; z}
+homogeneous!{Point3d, Point4d; x, y, z; w}
+homogeneous!{Vector3d, Vector4d; x, y, z; w}
+
+/// A rational bezier curve, which has a weight attached to each of its points. Implemented for `RBez` and
+/// `RNBez`, which override the `BezCurve` functions that work differently for rational curves.
+pub trait RationalCurve<F: Float>: OwnedBezCurve<F> {
+    /// Create a curve from a slice of points and a slice of their weights. Fails if either slice's
+    /// length is not a valid number of points for the curve.
+    fn from_weighted_slice(points: &[Self::Point], weights: &[F]) -> Option<Self>;
+
+    /// Get the weights of the curve's points.
+    fn weights(&self) -> Cow<'_, [F]>;
+}
+
+/// Project homogeneous points back down, returning the points and their weights.
+fn project_points<F, P>(lifted: &[P::Lifted]) -> (Vec<P>, Vec<F>)
+        where F: Float,
+              P: Homogeneous<F> {
+    lifted.iter().map(|h| P::project(*h)).unzip()
+}
+
+/// Get the weights of homogeneous points.
+fn lifted_weights<F, P>(lifted: &[P::Lifted]) -> Vec<F>
+        where F: Float,
+              P: Homogeneous<F> {
+    lifted.iter().map(|h| P::split_weight(*h).1).collect()
+}
+
+/// Evaluate the rational curve with the given homogeneous points at `t`, by evaluating the lifted curve
+/// and dividing out the weight. `lifted` gets used as scratch space.
+fn interp<F, P>(lifted: &mut [P::Lifted], t: F) -> P
+        where F: Float,
+              P: Homogeneous<F> {
+    let (point, weight) = P::split_weight(de_casteljau(lifted, t));
+    point / weight
+}
+
+/// Get the slope of the rational curve with the given homogeneous points at `t`.
+///
+/// With `a(t)` as the lifted curve's weighted components and `w(t)` as its weight, the curve is
+/// `c = a / w`. By the quotient rule, its slope is `(a' - c * w') / w`, and `a'` and `w'` are just the
+/// slope of the lifted curve.
+fn slope<F, P>(lifted: &[P::Lifted], t: F) -> P::Vector
+        where F: Float,
+              P: Homogeneous<F> {
+    let order = lifted.len() - 1;
+    if 0 == order {
+        return P::zero().into();
+    }
+
+    let mut dlifted: Vec<P::Lifted> = lifted.windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .collect();
+
+    let (numerator, weight) = P::split_weight(de_casteljau(&mut lifted.to_vec(), t));
+    let (dnumerator, dweight) = P::split_weight(de_casteljau(&mut dlifted, t) * F::from_usize(order).unwrap());
+    let point = numerator / weight;
+    ((dnumerator - point * dweight) / weight).into()
+}
+
+/// Get the numerator of the derivative of the rational curve whose lifted curve has the weighted
+/// points `numerators` and the weights `weights`. By the quotient rule, that's `a' * w - a * w'`,
+/// which is one order lower than the curve's squared weight, `w^2`, as its highest-order terms cancel
+/// out. A curve of order `0` has a numerator of zero.
+pub(crate) fn derivative_numerator<F, PV>(numerators: &[PV], weights: &[F]) -> Vec<PV>
+        where F: Float,
+              PV: Linear<F> {
+    let order = numerators.len() - 1;
+    if 0 == order {
+        return vec![PV::zero()];
+    }
+
+    let order_f = F::from_usize(order).unwrap();
+    let dnumerators: Vec<PV> = numerators.windows(2)
+        .map(|pair| (pair[1] - pair[0]) * order_f)
+        .collect();
+    let dweights: Vec<F> = weights.windows(2)
+        .map(|pair| (pair[1] - pair[0]) * order_f)
+        .collect();
+
+    bernstein_mul(&dnumerators, weights).into_iter()
+        .zip(bernstein_mul(numerators, &dweights))
+        .map(|(a, b)| a - b)
+        .collect()
+}
+
+/// Get the derivative of the rational curve with the given homogeneous points, as a rational curve of
+/// twice the order with `w^2` as its weights. See `derivative_numerator`.
+fn derivative<F, P>(lifted: &[P::Lifted]) -> RNBez<F, P::Vector>
+        where F: Float,
+              P: Homogeneous<F>,
+              P::Vector: Homogeneous<F> {
+    let (numerators, weights): (Vec<P>, Vec<F>) = lifted.iter().map(|h| P::split_weight(*h)).unzip();
+    let dweights = bernstein_mul(&weights, &weights);
+    let dnumerators = elevate_points(&derivative_numerator(&numerators, &weights), dweights.len() - 1);
+
+    let dlifted: Vec<_> = dnumerators.iter().zip(dweights.iter())
+        .map(|(numerator, weight)| Homogeneous::join_weight((*numerator).into(), *weight))
+        .collect();
+    RNBez::from_homogeneous(&dlifted)
+}
+
+/// Get the numerator of `value` along the rational curve with the given homogeneous points. See
+/// `BezCurve::numerator`.
+///
+/// As `value` is linear, `value(p) * w` is the same as `value(a) - value(0) * (1 - w)`, where `a = p * w`
+/// is the homogeneous point's weighted components. Finding the coefficients from `a` rather than from
+/// `p` keeps points at infinity, whose weight is zero, as the direction they're in instead of losing
+/// them.
+pub(crate) fn numerator<F, P, V, G>(lifted: &[P::Lifted], value: G) -> Vec<V>
+        where F: Float,
+              P: Homogeneous<F>,
+              V: Linear<F>,
+              G: Fn(P) -> V {
+    let origin = value(P::zero());
+    lifted.iter()
+        .map(|h| {
+            let (weighted, weight) = P::split_weight(*h);
+            value(weighted) - origin * (F::one() - weight)
+        })
+        .collect()
+}
+
+/// Elevate a rational curve to a polynomial curve of the given order, which only exists if the
+/// curve's weights are all the same. See `BezCurve::elevate_to`.
+pub(crate) fn elevate_to<F, B>(curve: &B, order: usize) -> Option<NBez<F, B::Point>>
+        where F: Float,
+              B: RationalCurve<F> {
+    // A rational curve with uniform weights is the same as the polynomial curve with its points.
+    let weights = curve.weights();
+    if order < curve.order() || weights.iter().any(|w| *w != weights[0]) {
+        None
+    } else {
+        Some(NBez::from_container(elevate_points(&curve.points(), order)))
+    }
+}
+
+/// Reduce a rational curve by fitting its lifted curve in homogeneous coordinates, where it's an
+/// ordinary curve. The distance between the curves can't be bounded the same way as for polynomial
+/// curves, so it's estimated by sampling them. See `BezCurve::reduce`.
+pub(crate) fn reduce<F, P, B>(curve: &B) -> (B::Reduced, F)
+        where F: Float,
+              P: Homogeneous<F>,
+              B: RationalCurve<F, Point = P>,
+              B::Reduced: RationalCurve<F>,
+              P::Vector: MetricVector<F> {
+    let order = curve.order().saturating_sub(1);
+    let weights = reduce_points(&curve.weights(), order);
+    let (points, weights): (Vec<P>, Vec<F>) = reduce_points(&curve.numerator(|point| point), order).iter()
+        .zip(weights.iter())
+        .map(|(weighted, weight)| P::project(weighted.join_weight(*weight)))
+        .unzip();
+
+    let reduced = B::Reduced::from_weighted_slice(&points, &weights).unwrap();
+    let error = sampled_error(curve, &reduced);
+    (reduced, error)
+}
+
+/// Transform the rational curve with the given homogeneous points with a projective transform. See
+/// `BezCurve::transform_projective`.
+pub(crate) fn transform_projective<F, P, T>(lifted: &[P::Lifted], transform: &T) -> RNBez<F, P>
+        where F: Float,
+              P: Homogeneous<F>,
+              T: ProjectiveTransform<F, P> {
+    let transformed: Vec<P::Lifted> = lifted.iter().map(|h| transform.apply_homogeneous(*h)).collect();
+    RNBez::from_homogeneous(&transformed)
+}
+
+/// Transform each of the given homogeneous points, keeping their weights. See
+/// `OwnedBezCurve::transform`.
+///
+/// With `o` as where the transform moves the origin, a point `p` with the weight `w` is moved to
+/// `T(p) * w = T(a) - o * (1 - w)` in homogeneous coordinates, where `a = p * w`. For points at
+/// infinity, that's `T(a) - o`, which is only the linear part of the transform, as they're directions
+/// rather than positions.
+pub(crate) fn transform_lifted<F, P, T>(lifted: &[P::Lifted], transform: &T) -> Vec<P::Lifted>
+        where F: Float,
+              P: Homogeneous<F>,
+              T: Transform<F, P> {
+    let origin = transform.apply(P::zero());
+    lifted.iter()
+        .map(|h| {
+            let (weighted, weight) = P::split_weight(*h);
+            (transform.apply(weighted) - origin * (F::one() - weight)).join_weight(weight)
+        })
+        .collect()
+}
+
+/// A stack-allocated rational bezier curve of order `N`, which is a `Bez` with a weight attached to
+/// each of its points.
+///
+/// Each point pulls the curve towards itself in proportion to its weight. Unlike polynomial curves,
+/// rational curves can represent conic sections exactly, and stay exact under projective transforms.
+/// The curve is stored as an ordinary bezier curve one dimension higher in homogeneous coordinates,
+/// which gets divided by its weight upon evaluation. `BezCurve` is implemented for 2D and 3D points,
+/// which lift into `Point3d` and `Point4d`.
+///
+/// The points and weights that `points` and `weights` give are projections of the homogeneous points.
+/// Weights of zero make points at infinity, which are represented by their direction; see
+/// `Homogeneous`. They get evaluated, split, elevated, transformed, differentiated, bounded and
+/// intersected exactly, but the curve's bounds, intersections, projections and reduction rely on the
+/// curve staying within the convex hull of its points, which only holds for weights that aren't
+/// negative.
+#[derive(Clone, Copy)]
+pub struct RBez<F, P, const N: usize>
+        where F: Float,
+              P: Homogeneous<F> {
+    lifted: Bez<F, P::Lifted, N>
+}
+
+impl<F, P, const N: usize> RBez<F, P, N>
+        where F: Float,
+              P: Homogeneous<F> {
+    /// Create a curve from a slice of points and a slice of their weights. Fails if either slice's
+    /// length does not match the curve's order + 1.
+    pub(crate) fn from_slices_static(points: &[P], weights: &[F]) -> Option<RBez<F, P, N>> {
+        if points.len() != N + 1 || weights.len() != N + 1 {
+            return None;
+        }
+
+        let mut lifted = Bez::from_points(P::Lifted::zero(), [P::Lifted::zero(); N]);
+        for ((h, point), weight) in lifted.as_mut().iter_mut().zip(points).zip(weights) {
+            *h = point.lift(*weight);
+        }
+        Some(RBez { lifted })
+    }
+
+    /// Create a curve out of points in homogeneous coordinates. Fails if the slice's length does not
+    /// match the curve's order + 1.
+    pub fn from_homogeneous(lifted: &[P::Lifted]) -> Option<RBez<F, P, N>> {
+        Bez::from_slice_static(lifted).map(|lifted| RBez { lifted })
+    }
+
+    /// Get the curve's points in homogeneous coordinates, as an ordinary bezier curve.
+    #[inline]
+    pub fn homogeneous(&self) -> Bez<F, P::Lifted, N> {
+        self.lifted
+    }
+
+    /// Get the curve's points as a polynomial curve, which is what the curve would be if all of its
+    /// weights were the same.
+    pub fn unweighted(&self) -> Bez<F, P, N> {
+        let mut points = Bez::from_points(P::zero(), [P::zero(); N]);
+        for (point, h) in points.as_mut().iter_mut().zip(self.lifted.as_ref()) {
+            *point = P::project(*h).0;
+        }
+        points
+    }
+}
+
+/// Polynomial curves are rational curves with all of their weights being `1.0`.
+impl<F, P, const N: usize> From<Bez<F, P, N>> for RBez<F, P, N>
+        where F: Float,
+              P: Homogeneous<F> {
+    fn from(curve: Bez<F, P, N>) -> RBez<F, P, N> {
+        let mut lifted = Bez::from_points(P::Lifted::zero(), [P::Lifted::zero(); N]);
+        for (h, point) in lifted.as_mut().iter_mut().zip(curve.as_ref()) {
+            *h = point.lift(F::one());
+        }
+        RBez { lifted }
+    }
+}
+
+/// The elevated, derivative and reduced curves are heap-allocated `RNBez`es, as their orders can't be
+/// written in terms of `N` on a stable compiler.
+impl<F, P, const N: usize> BezCurve<F> for RBez<F, P, N>
+        where F: Float,
+              P: Homogeneous<F>,
+              P::Vector: Homogeneous<F> {
+    type Point = P;
+    type Elevated = RNBez<F, P>;
+    type Derivative = RNBez<F, P::Vector>;
+    type Reduced = RNBez<F, P>;
+
+    /// Get the curve's points, projected down from homogeneous coordinates.
+    fn points(&self) -> Cow<'_, [P]> {
+        Cow::Owned(self.unweighted().as_ref().to_vec())
+    }
+
+    fn interp_unbounded(&self, t: F) -> P {
+        interp(self.lifted.clone().as_mut(), t)
+    }
+
+    fn slope_unbounded(&self, t: F) -> P::Vector {
+        slope::<F, P>(self.lifted.as_ref(), t)
+    }
+
+    /// Get the second derivative for the given `t` with no range bounds. A rational curve's derivative
+    /// isn't made out of the differences between its points, so this is the slope of `derivative`.
+    fn second_derivative_unbounded(&self, t: F) -> P::Vector {
+        self.derivative().slope_unbounded(t)
+    }
+
+    fn elevate(&self) -> RNBez<F, P> {
+        RNBez { lifted: elevate_points(self.lifted.as_ref(), N + 1) }
+    }
+
+    /// Elevate the curve to the given order in a single step. Returns `None` if `order` is lower than
+    /// the curve's order, or if the curve's weights aren't all the same, as a polynomial curve can't
+    /// represent it.
+    fn elevate_to(&self, order: usize) -> Option<NBez<F, P>> {
+        elevate_to(self, order)
+    }
+
+    /// Get the curve's derivative, which is a rational curve of twice the order. The derivative of a
+    /// curve of order `0` is a single zero vector.
+    fn derivative(&self) -> RNBez<F, P::Vector> {
+        derivative::<F, P>(self.lifted.as_ref())
+    }
+
+    /// Reduce the curve order by fitting the curve in homogeneous coordinates. The returned distance
+    /// is an estimate found by sampling both curves.
+    fn reduce(&self) -> (RNBez<F, P>, F)
+            where P::Vector: MetricVector<F> {
+        reduce(self)
+    }
+
+    fn transform_projective<T>(&self, transform: &T) -> RNBez<F, P>
+            where T: ProjectiveTransform<F, P> {
+        transform_projective(self.lifted.as_ref(), transform)
+    }
+
+    fn numerator<V, G>(&self, value: G) -> Vec<V>
+            where V: Copy + Add<Output = V> + Sub<Output = V> + Mul<F, Output = V> + Div<F, Output = V> + Zero,
+                  G: Fn(P) -> V {
+        numerator::<F, P, V, G>(self.lifted.as_ref(), value)
+    }
+
+    fn derivative_numerator<V, G>(&self, value: G) -> Vec<V>
+            where V: Copy + Add<Output = V> + Sub<Output = V> + Mul<F, Output = V> + Div<F, Output = V> + Zero,
+                  G: Fn(P) -> V {
+        derivative_numerator(&self.numerator(value), &lifted_weights::<F, P>(self.lifted.as_ref()))
+    }
+
+    #[inline]
+    fn order(&self) -> usize {
+        N
+    }
+}
+
+impl<F, P, const N: usize> OwnedBezCurve<F> for RBez<F, P, N>
+        where F: Float,
+              P: Homogeneous<F>,
+              P::Vector: Homogeneous<F> {
+    /// Create a curve from a slice, with every point having a weight of `1.0`. Fails if the slice's
+    /// length does not match the curve's order + 1.
+    fn from_slice(slice: &[P]) -> Option<RBez<F, P, N>> {
+        Bez::from_slice_static(slice).map(RBez::from)
+    }
+
+    fn split_unbounded(&self, t: F) -> (RBez<F, P, N>, RBez<F, P, N>) {
+        let mut lerps = self.lifted;
+        let mut left = self.lifted;
+        let mut right = self.lifted;
+        de_casteljau_split(self.lifted.as_ref(), t, lerps.as_mut(), left.as_mut(), right.as_mut());
+
+        (RBez { lifted: left }, RBez { lifted: right })
+    }
+
+    fn transform<T>(&self, transform: &T) -> RBez<F, P, N>
+            where T: Transform<F, P> {
+        RBez::from_homogeneous(&transform_lifted(self.lifted.as_ref(), transform)).unwrap()
+    }
+
+    fn reverse(&self) -> RBez<F, P, N> {
+        let mut lifted = self.lifted;
+        lifted.as_mut().reverse();
+        RBez { lifted }
+    }
+}
+
+impl<F, P, const N: usize> RationalCurve<F> for RBez<F, P, N>
+        where F: Float,
+              P: Homogeneous<F>,
+              P::Vector: Homogeneous<F> {
+    #[inline]
+    fn from_weighted_slice(points: &[P], weights: &[F]) -> Option<RBez<F, P, N>> {
+        RBez::from_slices_static(points, weights)
+    }
+
+    fn weights(&self) -> Cow<'_, [F]> {
+        Cow::Owned(lifted_weights::<F, P>(self.lifted.as_ref()))
+    }
+}
+
+impl<F, P, const N: usize> Debug for RBez<F, P, N>
+        where F: Float,
+              P: Homogeneous<F> + Debug {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), ::std::fmt::Error> {
+        let (points, weights): (Vec<P>, Vec<F>) = project_points(self.lifted.as_ref());
+        f.debug_tuple("RBez")
+            .field(&points)
+            .field(&weights)
+            .finish()
+    }
+}
+
+/// A heap-allocated rational bezier curve of any order, which is an `NBez` with a weight attached to
+/// each of its points. See `RBez` for how rational curves work.
+#[derive(Clone)]
+pub struct RNBez<F, P = Point2d<F>>
+        where F: Float,
+              P: Homogeneous<F> {
+    lifted: Vec<P::Lifted>
+}
+
+impl<F, P> RNBez<F, P>
+        where F: Float,
+              P: Homogeneous<F> {
+    /// Create a new rational curve out of its points and the weight of each point. Panics if there are
+    /// no points, or if there isn't exactly one weight per point.
+    pub fn from_weighted(points: Vec<P>, weights: Vec<F>) -> RNBez<F, P> {
+        if points.is_empty() || points.len() != weights.len() {
+            panic!("Rational Bézier curves need at least one point, and exactly one weight per point")
+        }
+
+        RNBez {
+            lifted: points.iter().zip(weights.iter()).map(|(point, weight)| point.lift(*weight)).collect()
+        }
+    }
+
+    /// Create a new rational curve out of points in homogeneous coordinates. Panics if `lifted` is
+    /// empty.
+    pub fn from_homogeneous(lifted: &[P::Lifted]) -> RNBez<F, P> {
+        if lifted.is_empty() {
+            panic!("Rational Bézier curves need at least one point")
+        }
+
+        RNBez { lifted: lifted.to_vec() }
+    }
+
+    /// Get the curve's points in homogeneous coordinates.
+    #[inline]
+    pub fn homogeneous(&self) -> &[P::Lifted] {
+        &self.lifted
+    }
+
+    /// Unwrap the curve, returning its points and their weights.
+    #[inline]
+    pub fn unwrap(self) -> (Vec<P>, Vec<F>) {
+        project_points(&self.lifted)
+    }
+}
+
+/// Polynomial curves are rational curves with all of their weights being `1.0`.
+impl<F, P, C> From<NBez<F, P, C>> for RNBez<F, P>
+        where F: Float,
+              P: Homogeneous<F>,
+              C: PointContainer<P> {
+    fn from(curve: NBez<F, P, C>) -> RNBez<F, P> {
+        let points: &[P] = curve.as_ref();
+        RNBez {
+            lifted: points.iter().map(|point| point.lift(F::one())).collect()
+        }
+    }
+}
+
+impl<F, P> BezCurve<F> for RNBez<F, P>
+        where F: Float,
+              P: Homogeneous<F>,
+              P::Vector: Homogeneous<F> {
+    type Point = P;
+    type Elevated = RNBez<F, P>;
+    type Derivative = RNBez<F, P::Vector>;
+    type Reduced = RNBez<F, P>;
+
+    /// Get the curve's points, projected down from homogeneous coordinates.
+    fn points(&self) -> Cow<'_, [P]> {
+        Cow::Owned(project_points::<F, P>(&self.lifted).0)
+    }
+
+    fn interp_unbounded(&self, t: F) -> P {
+        interp(&mut self.lifted.clone(), t)
+    }
+
+    fn slope_unbounded(&self, t: F) -> P::Vector {
+        slope::<F, P>(&self.lifted, t)
+    }
+
+    /// Get the second derivative for the given `t` with no range bounds. A rational curve's derivative
+    /// isn't made out of the differences between its points, so this is the slope of `derivative`.
+    fn second_derivative_unbounded(&self, t: F) -> P::Vector {
+        self.derivative().slope_unbounded(t)
+    }
+
+    fn elevate(&self) -> RNBez<F, P> {
+        RNBez { lifted: elevate_points(&self.lifted, self.order() + 1) }
+    }
+
+    /// Elevate the curve to the given order in a single step. Returns `None` if `order` is lower than
+    /// the curve's order, or if the curve's weights aren't all the same, as a polynomial curve can't
+    /// represent it.
+    fn elevate_to(&self, order: usize) -> Option<NBez<F, P>> {
+        elevate_to(self, order)
+    }
+
+    /// Get the curve's derivative, which is a rational curve of twice the order. The derivative of a
+    /// curve of order `0` is a single zero vector.
+    fn derivative(&self) -> RNBez<F, P::Vector> {
+        derivative::<F, P>(&self.lifted)
+    }
+
+    /// Reduce the curve order by fitting the curve in homogeneous coordinates. The returned distance
+    /// is an estimate found by sampling both curves.
+    fn reduce(&self) -> (RNBez<F, P>, F)
+            where P::Vector: MetricVector<F> {
+        reduce(self)
+    }
+
+    fn transform_projective<T>(&self, transform: &T) -> RNBez<F, P>
+            where T: ProjectiveTransform<F, P> {
+        transform_projective(&self.lifted, transform)
+    }
+
+    fn numerator<V, G>(&self, value: G) -> Vec<V>
+            where V: Copy + Add<Output = V> + Sub<Output = V> + Mul<F, Output = V> + Div<F, Output = V> + Zero,
+                  G: Fn(P) -> V {
+        numerator::<F, P, V, G>(&self.lifted, value)
+    }
+
+    fn derivative_numerator<V, G>(&self, value: G) -> Vec<V>
+            where V: Copy + Add<Output = V> + Sub<Output = V> + Mul<F, Output = V> + Div<F, Output = V> + Zero,
+                  G: Fn(P) -> V {
+        derivative_numerator(&self.numerator(value), &lifted_weights::<F, P>(&self.lifted))
+    }
+
+    fn order(&self) -> usize {
+        self.lifted.len() - 1
+    }
+}
+
+impl<F, P> OwnedBezCurve<F> for RNBez<F, P>
+        where F: Float,
+              P: Homogeneous<F>,
+              P::Vector: Homogeneous<F> {
+    /// Create a curve from a slice, with every point having a weight of `1.0`. Fails if the slice is
+    /// empty.
+    fn from_slice(slice: &[P]) -> Option<RNBez<F, P>> {
+        if slice.is_empty() {
+            None
+        } else {
+            Some(RNBez::from_weighted(slice.to_vec(), vec![F::one(); slice.len()]))
+        }
+    }
+
+    fn split_unbounded(&self, t: F) -> (RNBez<F, P>, RNBez<F, P>) {
+        let mut lerps = self.lifted.clone();
+        let mut left = self.lifted.clone();
+        let mut right = self.lifted.clone();
+        de_casteljau_split(&self.lifted, t, &mut lerps, &mut left, &mut right);
+
+        (RNBez { lifted: left }, RNBez { lifted: right })
+    }
+
+    fn transform<T>(&self, transform: &T) -> RNBez<F, P>
+            where T: Transform<F, P> {
+        RNBez { lifted: transform_lifted(&self.lifted, transform) }
+    }
+
+    fn reverse(&self) -> RNBez<F, P> {
+        RNBez { lifted: self.lifted.iter().rev().cloned().collect() }
+    }
+}
+
+impl<F, P> RationalCurve<F> for RNBez<F, P>
+        where F: Float,
+              P: Homogeneous<F>,
+              P::Vector: Homogeneous<F> {
+    fn from_weighted_slice(points: &[P], weights: &[F]) -> Option<RNBez<F, P>> {
+        if points.is_empty() || points.len() != weights.len() {
+            None
+        } else {
+            Some(RNBez::from_weighted(points.to_vec(), weights.to_vec()))
+        }
+    }
+
+    fn weights(&self) -> Cow<'_, [F]> {
+        Cow::Owned(lifted_weights::<F, P>(&self.lifted))
+    }
+}
+
+impl<F, P> Debug for RNBez<F, P>
+        where F: Float,
+              P: Homogeneous<F> + Debug {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), ::std::fmt::Error> {
+        let (points, weights): (Vec<P>, Vec<F>) = project_points(&self.lifted);
+        f.debug_tuple("RNBez")
+            .field(&points)
+            .field(&weights)
+            .finish()
+    }
+}
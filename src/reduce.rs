@@ -0,0 +1,106 @@
+use super::{BezCurve, Float, Linear, Point, MetricVector};
+use super::elevate::elevate_points;
+use super::solve::binomial;
+
+/// Get the integral of the product of the `i`th bernstein basis polynomial of order `m` and the `k`th
+/// of order `n`, over `0.0` to `1.0`.
+fn basis_product_integral<F: Float>(m: usize, i: usize, n: usize, k: usize) -> F {
+    binomial::<F>(m, i) * binomial(n, k) /
+        (binomial::<F>(m + n, i + k) * F::from_usize(m + n + 1).unwrap())
+}
+
+/// Get the points of the curve of the given order that best fits the curve with the given points, in
+/// the least squares sense.
+///
+/// The reduced curve keeps the original's end points, so the integral of the squared distance between
+/// the curves is minimized over the remaining points only. That gives a symmetric, positive definite
+/// linear system in those points, which is solved with Gaussian elimination. A reduced curve of order
+/// `0` can't keep both end points, so it gets the average of the curve instead.
+pub(crate) fn reduce_points<F, P>(points: &[P], order: usize) -> Vec<P>
+        where F: Float,
+              P: Linear<F> {
+    let n = points.len() - 1;
+    if order >= n {
+        return points.to_vec();
+    } else if 0 == order {
+        let sum = points.iter().fold(P::zero(), |acc, p| acc + *p);
+        return vec![sum / F::from_usize(points.len()).unwrap()];
+    }
+
+    let m = order;
+    let start = points[0];
+    let end = points[n];
+
+    // The system's unknowns are the interior points `1..m` of the reduced curve.
+    let size = m - 1;
+    let mut matrix: Vec<Vec<F>> = (1..m)
+        .map(|i| (1..m).map(|j| basis_product_integral(m, i, m, j)).collect())
+        .collect();
+    let mut rhs: Vec<P> = (1..m)
+        .map(|i| {
+            let target = points.iter().enumerate()
+                .fold(P::zero(), |acc, (k, p)| acc + *p * basis_product_integral(m, i, n, k));
+            target - start * basis_product_integral(m, i, m, 0) - end * basis_product_integral(m, i, m, m)
+        })
+        .collect();
+
+    // The matrix is positive definite, so elimination doesn't need any pivoting.
+    for col in 0..size {
+        let (upper, lower) = matrix.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value = *value - *pivot_value * factor;
+            }
+            rhs[col + 1 + offset] = rhs[col + 1 + offset] - rhs[col] * factor;
+        }
+    }
+    for row in (0..size).rev() {
+        let mut acc = rhs[row];
+        for k in row + 1..size {
+            acc = acc - rhs[k] * matrix[row][k];
+        }
+        rhs[row] = acc / matrix[row][row];
+    }
+
+    let mut reduced = Vec::with_capacity(m + 1);
+    reduced.push(start);
+    reduced.extend(rhs);
+    reduced.push(end);
+    reduced
+}
+
+/// Get an upper bound on the distance between the curves with the given points, which must be of the
+/// same or lower order than `points`.
+///
+/// Once `reduced` is elevated to the same order as `points`, the difference between the curves is a
+/// curve whose points are the differences between the two sets of points. The difference curve lies
+/// within the convex hull of its points, so it can't be any further from zero than they are.
+pub(crate) fn error_bound<F, P>(points: &[P], reduced: &[P]) -> F
+        where F: Float,
+              P: Point<F>,
+              P::Vector: MetricVector<F> {
+    let elevated = elevate_points(reduced, points.len() - 1);
+    points.iter().zip(elevated.iter())
+        .map(|(p, e)| (*p - *e).into().length())
+        .fold(F::zero(), F::max)
+}
+
+/// Estimate the distance between two curves by sampling both of them at the same `t`s. Unlike
+/// `error_bound`, this isn't a strict upper bound, but it works for rational curves, whose difference
+/// isn't a curve that can be bounded by its points.
+pub(crate) fn sampled_error<F, A, B>(a: &A, b: &B) -> F
+        where F: Float,
+              A: BezCurve<F>,
+              B: BezCurve<F, Point = A::Point>,
+              <A::Point as Point<F>>::Vector: MetricVector<F> {
+    let samples = 16 * (a.order() + 1);
+    let samples_f = F::from_usize(samples).unwrap();
+    (0..samples + 1)
+        .map(|i| {
+            let t = F::from_usize(i).unwrap() / samples_f;
+            (a.interp_unbounded(t) - b.interp_unbounded(t)).into().length()
+        })
+        .fold(F::zero(), F::max)
+}
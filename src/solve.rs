@@ -0,0 +1,271 @@
+use super::{Float, Linear, de_casteljau_split};
+
+/// The maximum number of iterations `find_root` performs before giving up on further refinement.
+const MAX_ITERATIONS: u32 = 100;
+
+/// Find a root of `f` between `lower` and `upper`, given `f_lower = f(lower)` and `f_upper = f(upper)`
+/// with opposite signs (or either being zero).
+///
+/// This uses the Illinois variant of regula falsi, which keeps the root bracketed like bisection does
+/// but converges superlinearly on smooth functions. Iteration stops once the bracket can't be
+/// narrowed any further.
+pub(crate) fn find_root<F, G>(f: G, mut lower: F, mut upper: F, mut f_lower: F, mut f_upper: F) -> F
+        where F: Float,
+              G: Fn(F) -> F {
+    let half = F::from_f32(0.5).unwrap();
+
+    // Which end of the bracket was moved last. Whenever the same end is moved twice in a row, the
+    // other end's value gets halved, which stops that end from getting stuck.
+    let mut last_moved = 0;
+
+    for _ in 0..MAX_ITERATIONS {
+        if f_lower == F::zero() {
+            return lower;
+        } else if f_upper == F::zero() {
+            return upper;
+        }
+
+        let mut mid = (lower * f_upper - upper * f_lower) / (f_upper - f_lower);
+        // Guard against the secant producing a point outside of the bracket due to rounding.
+        if !(lower < mid && mid < upper) {
+            mid = (lower + upper) * half;
+            if !(lower < mid && mid < upper) {
+                break;
+            }
+        }
+
+        let f_mid = f(mid);
+        if (f_mid < F::zero()) == (f_lower < F::zero()) {
+            lower = mid;
+            f_lower = f_mid;
+            if last_moved == -1 {
+                f_upper = f_upper * half;
+            }
+            last_moved = -1;
+        } else {
+            upper = mid;
+            f_upper = f_mid;
+            if last_moved == 1 {
+                f_lower = f_lower * half;
+            }
+            last_moved = 1;
+        }
+    }
+
+    if f_lower.abs() < f_upper.abs() {
+        lower
+    } else {
+        upper
+    }
+}
+
+/// The maximum number of times `bernstein_roots` subdivides an interval. Intervals that still can't be
+/// shown to contain at most one root at this depth contain a cluster of roots too close together to
+/// tell apart, and get reported as a single root.
+const MAX_SUBDIVISIONS: u32 = 48;
+
+/// Evaluate the one-dimensional bernstein polynomial with the given coefficients at `t`.
+///
+/// This is the same scaled Horner's method `NBez` uses, but with the binomial coefficients built up as
+/// the polynomial is evaluated, so that it doesn't need any scratch space.
+pub(crate) fn bernstein_eval<F: Float>(coeffs: &[F], t: F) -> F {
+    let one = F::one();
+    let n = coeffs.len() - 1;
+    let mut binomial = one;
+
+    if t <= F::from_f32(0.5).unwrap() {
+        let t1 = one - t;
+        let s = t / t1;
+        let mut acc = coeffs[n];
+        for k in (0..n).rev() {
+            binomial = binomial * F::from_usize(k + 1).unwrap() / F::from_usize(n - k).unwrap();
+            acc = acc * s + coeffs[k] * binomial;
+        }
+        acc * t1.powi(n as i32)
+    } else {
+        let s = (one - t) / t;
+        let mut acc = coeffs[0];
+        for (k, coeff) in coeffs.iter().enumerate().skip(1) {
+            binomial = binomial * F::from_usize(n - k + 1).unwrap() / F::from_usize(k).unwrap();
+            acc = acc * s + *coeff * binomial;
+        }
+        acc * t.powi(n as i32)
+    }
+}
+
+/// Get the binomial coefficient `n choose k`.
+pub(crate) fn binomial<F: Float>(n: usize, k: usize) -> F {
+    let k = k.min(n - k);
+    (0..k).fold(F::one(), |acc, i| acc * F::from_usize(n - i).unwrap() / F::from_usize(i + 1).unwrap())
+}
+
+/// Multiply two bernstein polynomials, getting the coefficients of a polynomial whose order is the sum
+/// of their orders. `b` is one-dimensional, but `a` can have points or vectors as its coefficients.
+pub(crate) fn bernstein_mul<F, PV>(a: &[PV], b: &[F]) -> Vec<PV>
+        where F: Float,
+              PV: Linear<F> {
+    bernstein_mul_with(a, b, |a, b| a * b)
+}
+
+/// Multiply two bernstein polynomials, with `product` multiplying their coefficients together. This
+/// allows for products that `bernstein_mul` can't express, such as the dot product of two curves of
+/// vectors.
+///
+/// Each coefficient of the product is a weighted sum of products of the factors' coefficients:
+/// `c[i] = sum(C(m, j) * C(k, i - j) / C(m + k, i) * product(a[j], b[i - j]))`, where `m` and `k` are
+/// the orders of `a` and `b`.
+pub(crate) fn bernstein_mul_with<F, A, B, C, G>(a: &[A], b: &[B], product: G) -> Vec<C>
+        where F: Float,
+              A: Copy,
+              B: Copy,
+              C: Linear<F>,
+              G: Fn(A, B) -> C {
+    let m = a.len() - 1;
+    let k = b.len() - 1;
+    let mut coeffs = vec![C::zero(); m + k + 1];
+
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        let start = i.saturating_sub(k);
+        let end = i.min(m);
+        for j in start..end + 1 {
+            *coeff = *coeff + product(a[j], b[i - j]) * (binomial::<F>(m, j) * binomial(k, i - j));
+        }
+        *coeff = *coeff / binomial(m + k, i);
+    }
+
+    coeffs
+}
+
+/// Remove any roots at the ends of the polynomial's interval by dividing them out, which leaves a
+/// polynomial with non-zero coefficients at both ends.
+///
+/// Removing a trailing zero coefficient `c[n]` means dividing by `1 - t`, which scales each remaining
+/// coefficient `c[k]` by `n / (n - k)`. Removing a leading zero does the same with `t`, scaling each
+/// `c[k]` by `n / k`. Both scale factors are positive, so the signs of the coefficients are unchanged.
+fn deflate<F: Float>(coeffs: &mut Vec<F>) {
+    while coeffs.len() > 1 && coeffs[coeffs.len() - 1] == F::zero() {
+        coeffs.pop();
+        let n = F::from_usize(coeffs.len()).unwrap();
+        for (k, coeff) in coeffs.iter_mut().enumerate() {
+            *coeff = *coeff * n / (n - F::from_usize(k).unwrap());
+        }
+    }
+
+    while coeffs.len() > 1 && coeffs[0] == F::zero() {
+        coeffs.remove(0);
+        let n = F::from_usize(coeffs.len()).unwrap();
+        for (k, coeff) in coeffs.iter_mut().enumerate() {
+            *coeff = *coeff * n / F::from_usize(k + 1).unwrap();
+        }
+    }
+}
+
+/// Get the `u` on `[0, 1]` at which the bernstein polynomial is closest to zero, out of the points the
+/// polynomial's coefficients sit above.
+fn closest_to_zero<F: Float>(coeffs: &[F]) -> F {
+    let n = F::from_usize(coeffs.len() - 1).unwrap();
+    (0..coeffs.len())
+        .map(|k| F::from_usize(k).unwrap() / n)
+        .map(|u| (u, bernstein_eval(coeffs, u).abs()))
+        .fold((F::zero(), F::infinity()), |closest, (u, value)| if value < closest.1 {(u, value)} else {closest})
+        .0
+}
+
+/// Find the roots of the bernstein polynomial on the interval `[t0, t1]`, pushing them onto `roots` in
+/// increasing order. Roots at the ends of the interval are ignored.
+///
+/// By the variation diminishing property, the number of roots in the interval is at most the number of
+/// sign changes in the coefficients. So, intervals with no sign changes get discarded, intervals with
+/// one sign change get their root found with `find_root`, and everything else gets split in half.
+///
+/// Roots of even multiplicity touch zero without changing its sign, so the coefficients around them
+/// never settle on a single sign change, and rounding errors can make the sign changes vanish
+/// altogether. Intervals whose coefficients are all within `noise` of zero, along with intervals with
+/// multiple sign changes that are narrower than `tolerance`, are reported as a single root wherever
+/// the polynomial is closest to zero.
+fn bernstein_roots_in<F: Float>(mut coeffs: Vec<F>, t0: F, t1: F, noise: F, tolerance: F, depth: u32, roots: &mut Vec<F>) {
+    deflate(&mut coeffs);
+    if coeffs.len() < 2 || coeffs.iter().all(|c| *c == F::zero()) {
+        return;
+    }
+
+    let mut sign_changes = 0;
+    let mut last_negative = coeffs[0] < F::zero();
+    for coeff in coeffs.iter().filter(|c| **c != F::zero()) {
+        if (*coeff < F::zero()) != last_negative {
+            sign_changes += 1;
+            last_negative = !last_negative;
+        }
+    }
+
+    let half = F::from_f32(0.5).unwrap();
+    let mid = (t0 + t1) * half;
+    if 1 == sign_changes {
+        let n = coeffs.len() - 1;
+        let u = find_root(|u| bernstein_eval(&coeffs, u), F::zero(), F::one(), coeffs[0], coeffs[n]);
+        roots.push(t0 + (t1 - t0) * u);
+        return;
+    } else if coeffs.iter().all(|c| c.abs() <= noise) {
+        roots.push(t0 + (t1 - t0) * closest_to_zero(&coeffs));
+        return;
+    } else if 0 == sign_changes {
+        return;
+    } else if depth >= MAX_SUBDIVISIONS || t1 - t0 <= tolerance || !(t0 < mid && mid < t1) {
+        roots.push(t0 + (t1 - t0) * closest_to_zero(&coeffs));
+        return;
+    }
+
+    let mut lerps = coeffs.clone();
+    let mut left = coeffs.clone();
+    let mut right = coeffs.clone();
+    de_casteljau_split(&coeffs, half, &mut lerps, &mut left, &mut right);
+    let mid_is_root = right[0] == F::zero();
+
+    bernstein_roots_in(left, t0, mid, noise, tolerance, depth + 1, roots);
+    if mid_is_root {
+        roots.push(mid);
+    }
+    bernstein_roots_in(right, mid, t1, noise, tolerance, depth + 1, roots);
+}
+
+/// Find the roots of the one-dimensional bernstein polynomial with the given coefficients on the
+/// interval `[0, 1]`, in increasing order. A polynomial that is zero everywhere has no isolated roots,
+/// so it returns an empty vector.
+///
+/// Roots closer together than the square root of `F`'s epsilon, which is about as precisely as a
+/// double root can be located, are merged into whichever of them is closest to zero.
+pub(crate) fn bernstein_roots<F: Float>(coeffs: &[F]) -> Vec<F> {
+    let mut roots = Vec::new();
+    if coeffs.is_empty() || coeffs.iter().all(|c| *c == F::zero()) {
+        return roots;
+    }
+
+    // Each subdivision can add rounding errors on the order of epsilon times the largest coefficient,
+    // so coefficients smaller than the errors from the deepest subdivisions are indistinguishable from
+    // zero.
+    let scale = coeffs.iter().fold(F::zero(), |acc, c| acc.max(c.abs()));
+    let noise = scale * F::epsilon() * F::from_u32(MAX_SUBDIVISIONS).unwrap();
+    let tolerance = F::epsilon().sqrt();
+
+    if coeffs[0] == F::zero() {
+        roots.push(F::zero());
+    }
+    bernstein_roots_in(coeffs.to_vec(), F::zero(), F::one(), noise, tolerance, 0, &mut roots);
+    if coeffs[coeffs.len() - 1] == F::zero() {
+        roots.push(F::one());
+    }
+
+    let mut merged: Vec<F> = Vec::with_capacity(roots.len());
+    for root in roots {
+        match merged.last_mut() {
+            Some(last) if root - *last <= tolerance => {
+                if bernstein_eval(coeffs, root).abs() < bernstein_eval(coeffs, *last).abs() {
+                    *last = root;
+                }
+            },
+            _ => merged.push(root)
+        }
+    }
+
+    merged
+}
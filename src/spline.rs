@@ -0,0 +1,430 @@
+use std::convert::AsRef;
+use std::iter;
+
+use super::{OwnedBezCurve, Float, Homogeneous, RationalCurve, Lerp, Linear, Point, Point2d, lerp};
+
+/// A B-spline curve of any degree, made out of polynomial pieces that join smoothly at its knots.
+///
+/// The knot vector has `degree + 1` more knots than there are control points, and never decreases.
+/// The curve is defined between the knots at `degree` and `points.len()`, and each interval between
+/// two adjacent knots in that range is one piece of the curve, which only depends on `degree + 1` of
+/// the control points. Repeating a knot makes the curve less smooth there, and repeating the knots at
+/// either end `degree + 1` times, like `uniform_clamped` does, makes the curve start and end at its
+/// first and last control points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BSpline<F, P = Point2d<F>>
+        where F: Float,
+              P: Point<F> {
+    degree: usize,
+    knots: Vec<F>,
+    points: Vec<P>
+}
+
+impl<F, P> BSpline<F, P>
+        where F: Float,
+              P: Point<F> {
+    /// Create a spline out of its degree, knot vector and control points. Fails if there aren't
+    /// `degree + 1` more knots than points, if there are fewer than `degree + 1` points, if the knots
+    /// ever decrease, or if the curve's domain is empty.
+    pub fn new(degree: usize, knots: Vec<F>, points: Vec<P>) -> Option<BSpline<F, P>> {
+        if points.len() <= degree || knots.len() != points.len() + degree + 1 ||
+                knots.windows(2).any(|pair| pair[1] < pair[0]) ||
+                knots[degree] >= knots[points.len()] {
+            None
+        } else {
+            Some(BSpline {
+                degree,
+                knots,
+                points
+            })
+        }
+    }
+
+    /// Create a spline whose end knots are repeated `degree + 1` times and whose interior knots are
+    /// evenly spaced, with a domain of `0.0` to `1.0`. The spline starts at the first point and ends at
+    /// the last one. Fails if there are fewer than `degree + 1` points.
+    pub fn uniform_clamped(degree: usize, points: Vec<P>) -> Option<BSpline<F, P>> {
+        if points.len() <= degree {
+            return None;
+        }
+
+        let spans = points.len() - degree;
+        let spans_f = F::from_usize(spans).unwrap();
+        let knots = (0..points.len() + degree + 1)
+            .map(|i| F::from_usize(i.saturating_sub(degree).min(spans)).unwrap() / spans_f)
+            .collect();
+        BSpline::new(degree, knots, points)
+    }
+
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    #[inline]
+    pub fn knots(&self) -> &[F] {
+        &self.knots
+    }
+
+    /// Get the first and last values that the curve is defined at.
+    #[inline]
+    pub fn domain(&self) -> (F, F) {
+        (self.knots[self.degree], self.knots[self.points.len()])
+    }
+
+    /// Unwrap the spline, returning its degree, knot vector and control points.
+    #[inline]
+    pub fn unwrap(self) -> (usize, Vec<F>, Vec<P>) {
+        (self.degree, self.knots, self.points)
+    }
+
+    /// Perform interpolation on the curve for the given `u`, bounded on the curve's domain. Returns
+    /// `None` if `u` is not within bounds.
+    pub fn interp(&self, u: F) -> Option<P> {
+        let (start, end) = self.domain();
+        if u < start || u > end {
+            None
+        } else {
+            Some(self.interp_unbounded(u))
+        }
+    }
+
+    /// Perform interpolation on the curve with no range bounds, with de Boor's algorithm. Outside of
+    /// the domain, the first or last piece of the curve gets extended.
+    pub fn interp_unbounded(&self, u: F) -> P {
+        let span = find_span(self.degree, &self.knots, u);
+        blossom(self.degree, &self.knots, &self.points, span, iter::repeat(u).take(self.degree))
+    }
+
+    /// Get the slope for the given `u`, bounded on the curve's domain. Returns `None` if `u` is not
+    /// within bounds.
+    pub fn slope(&self, u: F) -> Option<P::Vector> {
+        let (start, end) = self.domain();
+        if u < start || u > end {
+            None
+        } else {
+            Some(self.slope_unbounded(u))
+        }
+    }
+
+    /// Get the slope for the given `u` with no range bounds. This is the same as interpolating the
+    /// derivative, without creating the whole derivative spline.
+    pub fn slope_unbounded(&self, u: F) -> P::Vector {
+        slope(self.degree, &self.knots, &self.points, u).into()
+    }
+
+    /// Get the derivative of the spline, which is a spline one degree lower with the first and last
+    /// knots removed. Its points are the differences between adjacent points, scaled by the degree and
+    /// divided by the width of the knots they span. The derivative of a spline of degree `0` is zero
+    /// everywhere.
+    pub fn derivative(&self) -> BSpline<F, P::Vector> {
+        let (degree, knots, points) = derivative(self.degree, &self.knots, &self.points);
+        BSpline {
+            degree,
+            knots,
+            points: points.into_iter().map(|point| point.into()).collect()
+        }
+    }
+
+    /// Insert a knot at `u` without changing the shape of the curve, using Boehm's algorithm. Returns
+    /// `None` if `u` isn't within the curve's domain, or is at the very end of it.
+    pub fn insert_knot(&self, u: F) -> Option<BSpline<F, P>> {
+        let (knots, points) = insert_knot(self.degree, &self.knots, &self.points, u)?;
+        Some(BSpline {
+            degree: self.degree,
+            knots,
+            points
+        })
+    }
+
+    /// Split the spline into the bezier curves that make up each of its pieces, in order. Pieces that
+    /// are empty because of repeated knots are skipped. The curves can be of any type whose order
+    /// matches the spline's degree, which would usually be an `NBez` or the `Bez` of that order.
+    /// Returns `None` if `B` can't hold a curve of the spline's degree.
+    pub fn segments<B>(&self) -> Option<Vec<B>>
+            where B: OwnedBezCurve<F, Point = P> {
+        segment_points(self.degree, &self.knots, &self.points).iter()
+            .map(|points| B::from_slice(points))
+            .collect()
+    }
+}
+
+impl<F, P> AsRef<[P]> for BSpline<F, P>
+        where F: Float,
+              P: Point<F> {
+    fn as_ref(&self) -> &[P] {
+        &self.points
+    }
+}
+
+/// A rational B-spline, or NURBS curve, which is a `BSpline` with a weight attached to each of its
+/// points. Like rational bezier curves, it's stored as a spline in homogeneous coordinates, and its
+/// points get divided by its weight upon evaluation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Nurbs<F, P = Point2d<F>>
+        where F: Float,
+              P: Homogeneous<F> {
+    spline: BSpline<F, P::Lifted>
+}
+
+impl<F, P> Nurbs<F, P>
+        where F: Float,
+              P: Homogeneous<F> {
+    /// Create a NURBS curve out of its degree, knot vector, control points and the weight of each
+    /// point. Fails if there isn't exactly one weight per point, or for any of the reasons that
+    /// `BSpline::new` fails.
+    pub fn new(degree: usize, knots: Vec<F>, points: &[P], weights: &[F]) -> Option<Nurbs<F, P>> {
+        if points.len() != weights.len() {
+            return None;
+        }
+
+        let lifted = points.iter().zip(weights.iter())
+            .map(|(point, weight)| point.lift(*weight))
+            .collect();
+        BSpline::new(degree, knots, lifted).map(Nurbs::from_homogeneous)
+    }
+
+    /// Create a NURBS curve out of a spline in homogeneous coordinates.
+    #[inline]
+    pub fn from_homogeneous(spline: BSpline<F, P::Lifted>) -> Nurbs<F, P> {
+        Nurbs {
+            spline
+        }
+    }
+
+    /// Get the spline in homogeneous coordinates that the curve is a projection of.
+    #[inline]
+    pub fn homogeneous(&self) -> &BSpline<F, P::Lifted> {
+        &self.spline
+    }
+
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.spline.degree
+    }
+
+    #[inline]
+    pub fn knots(&self) -> &[F] {
+        &self.spline.knots
+    }
+
+    /// Get the first and last values that the curve is defined at.
+    #[inline]
+    pub fn domain(&self) -> (F, F) {
+        self.spline.domain()
+    }
+
+    /// Get the curve's control points, without their weights.
+    pub fn points(&self) -> Vec<P> {
+        self.spline.points.iter().map(|h| P::project(*h).0).collect()
+    }
+
+    /// Get the weight of each of the curve's control points.
+    pub fn weights(&self) -> Vec<F> {
+        self.spline.points.iter().map(|h| P::split_weight(*h).1).collect()
+    }
+
+    /// Perform interpolation on the curve for the given `u`, bounded on the curve's domain. Returns
+    /// `None` if `u` is not within bounds.
+    pub fn interp(&self, u: F) -> Option<P> {
+        let (start, end) = self.domain();
+        if u < start || u > end {
+            None
+        } else {
+            Some(self.interp_unbounded(u))
+        }
+    }
+
+    /// Perform interpolation on the curve with no range bounds
+    pub fn interp_unbounded(&self, u: F) -> P {
+        let (point, weight) = P::split_weight(self.spline.interp_unbounded(u));
+        point / weight
+    }
+
+    /// Get the slope for the given `u`, bounded on the curve's domain. Returns `None` if `u` is not
+    /// within bounds.
+    pub fn slope(&self, u: F) -> Option<P::Vector> {
+        let (start, end) = self.domain();
+        if u < start || u > end {
+            None
+        } else {
+            Some(self.slope_unbounded(u))
+        }
+    }
+
+    /// Get the slope for the given `u` with no range bounds. The same as for rational bezier curves,
+    /// the slope comes from applying the quotient rule to the homogeneous spline and its derivative.
+    pub fn slope_unbounded(&self, u: F) -> P::Vector {
+        let (numerator, weight) = P::split_weight(self.spline.interp_unbounded(u));
+        let spline = &self.spline;
+        let (dnumerator, dweight) = P::split_weight(slope(spline.degree, &spline.knots, &spline.points, u));
+        let point = numerator / weight;
+        ((dnumerator - point * dweight) / weight).into()
+    }
+
+    /// Insert a knot at `u` without changing the shape of the curve. The knot gets inserted into the
+    /// homogeneous spline, so the weights change along with the points. Returns `None` if `u` isn't
+    /// within the curve's domain, or is at the very end of it.
+    pub fn insert_knot(&self, u: F) -> Option<Nurbs<F, P>> {
+        self.spline.insert_knot(u).map(Nurbs::from_homogeneous)
+    }
+
+    /// Split the curve into the rational bezier curves that make up each of its pieces, in order. See
+    /// `BSpline::segments`. Returns `None` if `B` can't hold a curve of the spline's degree.
+    pub fn segments<B>(&self) -> Option<Vec<B>>
+            where B: RationalCurve<F, Point = P> {
+        segment_points(self.spline.degree, &self.spline.knots, &self.spline.points).iter()
+            .map(|lifted| {
+                let (points, weights): (Vec<P>, Vec<F>) = lifted.iter().map(|h| P::project(*h)).unzip();
+                B::from_weighted_slice(&points, &weights)
+            })
+            .collect()
+    }
+}
+
+/// Polynomial splines are NURBS curves with all of their weights being `1.0`.
+impl<F, P> From<BSpline<F, P>> for Nurbs<F, P>
+        where F: Float,
+              P: Homogeneous<F> {
+    fn from(spline: BSpline<F, P>) -> Nurbs<F, P> {
+        Nurbs::from_homogeneous(BSpline {
+            degree: spline.degree,
+            knots: spline.knots,
+            points: spline.points.iter().map(|point| point.lift(F::one())).collect()
+        })
+    }
+}
+
+/// Get the degree, knots and points of the derivative of the spline. See `BSpline::derivative`.
+fn derivative<F, PV>(degree: usize, knots: &[F], points: &[PV]) -> (usize, Vec<F>, Vec<PV>)
+        where F: Float,
+              PV: Linear<F> {
+    if 0 == degree {
+        return (0, knots.to_vec(), vec![PV::zero(); points.len()]);
+    }
+
+    let degree_f = F::from_usize(degree).unwrap();
+    let dpoints = points.windows(2).enumerate()
+        .map(|(i, pair)| {
+            let width = knots[i + degree + 1] - knots[i + 1];
+            if width == F::zero() {
+                PV::zero()
+            } else {
+                (pair[1] - pair[0]) * (degree_f / width)
+            }
+        })
+        .collect();
+
+    (degree - 1, knots[1..knots.len() - 1].to_vec(), dpoints)
+}
+
+/// Get the index of the knot that starts the piece of the curve containing `u`. That's the last
+/// non-empty piece that starts at or before `u`, or the first non-empty piece if `u` is before all of
+/// them.
+fn find_span<F: Float>(degree: usize, knots: &[F], u: F) -> usize {
+    let last = knots.len() - degree - 2;
+    let mut spans = (degree..last + 1).filter(|k| knots[*k] < knots[*k + 1]);
+    let first = spans.next().unwrap();
+    spans.filter(|k| knots[*k] <= u).last().unwrap_or(first)
+}
+
+/// Get the slope of the spline at `u`. The points of the derivative that the piece containing `u`
+/// depends on are the differences between that piece's points, so de Boor's algorithm is run one
+/// degree lower on those, with the derivative's knots. See `derivative`.
+fn slope<F, PV>(degree: usize, knots: &[F], points: &[PV], u: F) -> PV
+        where F: Float,
+              PV: Linear<F> {
+    if 0 == degree {
+        return PV::zero();
+    }
+
+    let span = find_span(degree, knots, u);
+    let base = span - degree;
+    let degree_f = F::from_usize(degree).unwrap();
+    let mut lerps: Vec<PV> = points[base..span + 1].windows(2).enumerate()
+        .map(|(j, pair)| {
+            let width = knots[base + j + degree + 1] - knots[base + j + 1];
+            if width == F::zero() {
+                PV::zero()
+            } else {
+                (pair[1] - pair[0]) * (degree_f / width)
+            }
+        })
+        .collect();
+
+    de_boor(degree - 1, &knots[1..knots.len() - 1], &mut lerps, span - 1, iter::repeat(u).take(degree - 1))
+}
+
+/// Evaluate the blossom, or polar form, of the piece of the spline starting at knot `span` with the
+/// given arguments, of which there must be `degree`.
+///
+/// This is de Boor's algorithm, with a different argument used on each level. Evaluating with every
+/// argument the same gives the point on the curve, and evaluating with the arguments at the ends of
+/// the piece gives the piece's bezier points.
+fn blossom<F, PV, A>(degree: usize, knots: &[F], points: &[PV], span: usize, args: A) -> PV
+        where F: Float,
+              PV: Lerp<F>,
+              A: IntoIterator<Item = F> {
+    de_boor(degree, knots, &mut points[span - degree..span + 1].to_vec(), span, args)
+}
+
+/// Evaluate the blossom like `blossom`, with `lerps` holding the `degree + 1` points that the piece
+/// starting at knot `span` depends on. `lerps` gets used as scratch space.
+fn de_boor<F, PV, A>(degree: usize, knots: &[F], lerps: &mut [PV], span: usize, args: A) -> PV
+        where F: Float,
+              PV: Lerp<F>,
+              A: IntoIterator<Item = F> {
+    let base = span - degree;
+    for (r, arg) in args.into_iter().enumerate().map(|(r, arg)| (r + 1, arg)) {
+        for j in (r..degree + 1).rev() {
+            let i = base + j;
+            let factor = (arg - knots[i]) / (knots[i + degree + 1 - r] - knots[i]);
+            lerps[j] = lerp(lerps[j - 1], lerps[j], factor);
+        }
+    }
+
+    lerps[degree]
+}
+
+/// Insert a knot at `u` with Boehm's algorithm, returning the new knot vector and points.
+///
+/// Only the `degree` points that affect the piece containing `u` change, with each of them replaced
+/// by a point on the line between it and the point before it.
+fn insert_knot<F, PV>(degree: usize, knots: &[F], points: &[PV], u: F) -> Option<(Vec<F>, Vec<PV>)>
+        where F: Float,
+              PV: Lerp<F> {
+    let span = find_span(degree, knots, u);
+    if u < knots[span] || u >= knots[span + 1] {
+        return None;
+    }
+
+    let mut new_points = Vec::with_capacity(points.len() + 1);
+    new_points.extend_from_slice(&points[..span + 1 - degree]);
+    for i in span + 1 - degree..span + 1 {
+        let factor = (u - knots[i]) / (knots[i + degree] - knots[i]);
+        new_points.push(lerp(points[i - 1], points[i], factor));
+    }
+    new_points.extend_from_slice(&points[span..]);
+
+    let mut new_knots = knots.to_vec();
+    new_knots.insert(span + 1, u);
+    Some((new_knots, new_points))
+}
+
+/// Get the bezier points of each non-empty piece of the spline, in order.
+fn segment_points<F, PV>(degree: usize, knots: &[F], points: &[PV]) -> Vec<Vec<PV>>
+        where F: Float,
+              PV: Lerp<F> {
+    (degree..points.len())
+        .filter(|span| knots[*span] < knots[*span + 1])
+        .map(|span| {
+            let (start, end) = (knots[span], knots[span + 1]);
+            (0..degree + 1)
+                .map(|j| {
+                    let args = iter::repeat(start).take(degree - j).chain(iter::repeat(end).take(j));
+                    blossom(degree, knots, points, span, args)
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,236 @@
+use super::{Float, Homogeneous, Point, Point2d, Point3d, Point4d, Vector3d};
+
+/// A transform that maps points onto other points of the same type.
+pub trait Transform<F: Float, P: Point<F>> {
+    /// Transform a single point
+    fn apply(&self, point: P) -> P;
+}
+
+/// A projective transform, which maps points in homogeneous coordinates onto other homogeneous points.
+/// The transformed point's weight generally differs from the original's, which is why transformed
+/// curves have to be rational.
+pub trait ProjectiveTransform<F: Float, P: Homogeneous<F>> {
+    /// Transform a single point in homogeneous coordinates
+    fn apply_homogeneous(&self, point: P::Lifted) -> P::Lifted;
+}
+
+/// Multiply the `R`x`C` matrix `a` by the `C`x`C` matrix `b`, where `b`'s last row is taken to be
+/// `[0, ..., 0, 1]` if `b` only has `C - 1` rows. That lets affine matrices be multiplied together as
+/// if they were square.
+fn mul_matrix<F: Float, const R: usize, const C: usize, const BR: usize>(a: &[[F; C]; R], b: &[[F; C]; BR]) -> [[F; C]; R] {
+    let b_row = |k: usize| -> [F; C] {
+        if k < BR {
+            b[k]
+        } else {
+            let mut row = [F::zero(); C];
+            row[C - 1] = F::one();
+            row
+        }
+    };
+
+    let mut product = [[F::zero(); C]; R];
+    for (row, a_row) in product.iter_mut().zip(a.iter()) {
+        for (k, a_value) in a_row.iter().enumerate() {
+            let b_values = b_row(k);
+            for (value, b_value) in row.iter_mut().zip(b_values.iter()) {
+                *value = *value + *a_value * *b_value;
+            }
+        }
+    }
+    product
+}
+
+/// An affine transform of 2D points, stored as the top two rows of a 3x3 matrix. Points are treated as
+/// column vectors, so `x' = matrix[0][0] * x + matrix[0][1] * y + matrix[0][2]`.
+///
+/// As bezier curves are built out of linear interpolations of their points, which affine transforms
+/// preserve, transforming a curve's points transforms the curve exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2d<F: Float> {
+    pub matrix: [[F; 3]; 2]
+}
+
+impl<F: Float> Transform2d<F> {
+    #[inline]
+    pub fn new(matrix: [[F; 3]; 2]) -> Transform2d<F> {
+        Transform2d {
+            matrix
+        }
+    }
+
+    /// Create a transform that leaves points where they are.
+    pub fn identity() -> Transform2d<F> {
+        Transform2d::scale(F::one(), F::one())
+    }
+
+    /// Create a transform that moves points by `x` and `y`.
+    pub fn translate(x: F, y: F) -> Transform2d<F> {
+        let (zero, one) = (F::zero(), F::one());
+        Transform2d::new([[one, zero, x], [zero, one, y]])
+    }
+
+    /// Create a transform that scales points away from the origin by `x` and `y`.
+    pub fn scale(x: F, y: F) -> Transform2d<F> {
+        let zero = F::zero();
+        Transform2d::new([[x, zero, zero], [zero, y, zero]])
+    }
+
+    /// Create a transform that rotates points counterclockwise around the origin by `angle` radians.
+    pub fn rotate(angle: F) -> Transform2d<F> {
+        let (sin, cos) = angle.sin_cos();
+        let zero = F::zero();
+        Transform2d::new([[cos, -sin, zero], [sin, cos, zero]])
+    }
+
+    /// Create a transform that applies `self` followed by `next`.
+    pub fn then(&self, next: &Transform2d<F>) -> Transform2d<F> {
+        Transform2d::new(mul_matrix(&next.matrix, &self.matrix))
+    }
+}
+
+impl<F: Float> Transform<F, Point2d<F>> for Transform2d<F> {
+    fn apply(&self, point: Point2d<F>) -> Point2d<F> {
+        let m = &self.matrix;
+        Point2d::new(
+            m[0][0] * point.x + m[0][1] * point.y + m[0][2],
+            m[1][0] * point.x + m[1][1] * point.y + m[1][2]
+        )
+    }
+}
+
+/// An affine transform of 3D points, stored as the top three rows of a 4x4 matrix. Like `Transform2d`,
+/// points are treated as column vectors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform3d<F: Float> {
+    pub matrix: [[F; 4]; 3]
+}
+
+impl<F: Float> Transform3d<F> {
+    #[inline]
+    pub fn new(matrix: [[F; 4]; 3]) -> Transform3d<F> {
+        Transform3d {
+            matrix
+        }
+    }
+
+    /// Create a transform that leaves points where they are.
+    pub fn identity() -> Transform3d<F> {
+        Transform3d::scale(F::one(), F::one(), F::one())
+    }
+
+    /// Create a transform that moves points by `x`, `y` and `z`.
+    pub fn translate(x: F, y: F, z: F) -> Transform3d<F> {
+        let (zero, one) = (F::zero(), F::one());
+        Transform3d::new([[one, zero, zero, x], [zero, one, zero, y], [zero, zero, one, z]])
+    }
+
+    /// Create a transform that scales points away from the origin by `x`, `y` and `z`.
+    pub fn scale(x: F, y: F, z: F) -> Transform3d<F> {
+        let zero = F::zero();
+        Transform3d::new([[x, zero, zero, zero], [zero, y, zero, zero], [zero, zero, z, zero]])
+    }
+
+    /// Create a transform that rotates points around `axis` by `angle` radians, counterclockwise when
+    /// looking back along the axis towards the origin. `axis` doesn't need to be normalized.
+    pub fn rotate(axis: Vector3d<F>, angle: F) -> Transform3d<F> {
+        let Vector3d { x, y, z } = axis.normalize();
+        let (sin, cos) = angle.sin_cos();
+        let cos1 = F::one() - cos;
+        let zero = F::zero();
+
+        Transform3d::new([
+            [cos + x * x * cos1, x * y * cos1 - z * sin, x * z * cos1 + y * sin, zero],
+            [y * x * cos1 + z * sin, cos + y * y * cos1, y * z * cos1 - x * sin, zero],
+            [z * x * cos1 - y * sin, z * y * cos1 + x * sin, cos + z * z * cos1, zero]
+        ])
+    }
+
+    /// Create a transform that applies `self` followed by `next`.
+    pub fn then(&self, next: &Transform3d<F>) -> Transform3d<F> {
+        Transform3d::new(mul_matrix(&next.matrix, &self.matrix))
+    }
+}
+
+impl<F: Float> Transform<F, Point3d<F>> for Transform3d<F> {
+    fn apply(&self, point: Point3d<F>) -> Point3d<F> {
+        let m = &self.matrix;
+        Point3d::new(
+            m[0][0] * point.x + m[0][1] * point.y + m[0][2] * point.z + m[0][3],
+            m[1][0] * point.x + m[1][1] * point.y + m[1][2] * point.z + m[1][3],
+            m[2][0] * point.x + m[2][1] * point.y + m[2][2] * point.z + m[2][3]
+        )
+    }
+}
+
+/// A projective transform of 2D points, stored as a 3x3 matrix. Unlike affine transforms, projective
+/// transforms don't preserve linear interpolation, so a transformed curve has to be rational.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projective2d<F: Float> {
+    pub matrix: [[F; 3]; 3]
+}
+
+impl<F: Float> Projective2d<F> {
+    #[inline]
+    pub fn new(matrix: [[F; 3]; 3]) -> Projective2d<F> {
+        Projective2d {
+            matrix
+        }
+    }
+
+    /// Create a transform that applies `self` followed by `next`.
+    pub fn then(&self, next: &Projective2d<F>) -> Projective2d<F> {
+        Projective2d::new(mul_matrix(&next.matrix, &self.matrix))
+    }
+}
+
+impl<F: Float> From<Transform2d<F>> for Projective2d<F> {
+    fn from(transform: Transform2d<F>) -> Projective2d<F> {
+        let m = transform.matrix;
+        let (zero, one) = (F::zero(), F::one());
+        Projective2d::new([m[0], m[1], [zero, zero, one]])
+    }
+}
+
+impl<F: Float> ProjectiveTransform<F, Point2d<F>> for Projective2d<F> {
+    fn apply_homogeneous(&self, point: Point3d<F>) -> Point3d<F> {
+        let m = &self.matrix;
+        let row = |r: usize| m[r][0] * point.x + m[r][1] * point.y + m[r][2] * point.z;
+        Point3d::new(row(0), row(1), row(2))
+    }
+}
+
+/// A projective transform of 3D points, stored as a 4x4 matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projective3d<F: Float> {
+    pub matrix: [[F; 4]; 4]
+}
+
+impl<F: Float> Projective3d<F> {
+    #[inline]
+    pub fn new(matrix: [[F; 4]; 4]) -> Projective3d<F> {
+        Projective3d {
+            matrix
+        }
+    }
+
+    /// Create a transform that applies `self` followed by `next`.
+    pub fn then(&self, next: &Projective3d<F>) -> Projective3d<F> {
+        Projective3d::new(mul_matrix(&next.matrix, &self.matrix))
+    }
+}
+
+impl<F: Float> From<Transform3d<F>> for Projective3d<F> {
+    fn from(transform: Transform3d<F>) -> Projective3d<F> {
+        let m = transform.matrix;
+        let (zero, one) = (F::zero(), F::one());
+        Projective3d::new([m[0], m[1], m[2], [zero, zero, zero, one]])
+    }
+}
+
+impl<F: Float> ProjectiveTransform<F, Point3d<F>> for Projective3d<F> {
+    fn apply_homogeneous(&self, point: Point4d<F>) -> Point4d<F> {
+        let m = &self.matrix;
+        let row = |r: usize| m[r][0] * point.x + m[r][1] * point.y + m[r][2] * point.z + m[r][3] * point.w;
+        Point4d::new(row(0), row(1), row(2), row(3))
+    }
+}